@@ -18,8 +18,10 @@ use same_file::{is_same_file, Handle};
 use siphasher::sip128::{Hasher128, SipHasher};
 use termcolor::{ColorChoice, StandardStream, WriteColor};
 use typst::diag::{FileError, FileResult, SourceError, StrResult};
+use typst::doc::Document;
 use typst::eval::Library;
 use typst::font::{Font, FontBook, FontInfo, FontVariant};
+use typst::geom::Color;
 use typst::syntax::{Source, SourceId};
 use typst::util::{Buffer, PathExt};
 use typst::World;
@@ -34,13 +36,14 @@ enum Command {
     Fonts(FontsCommand),
 }
 
-/// Compile a .typ file into a PDF file.
+/// Compile a .typ file into a PDF or PNG file.
 struct CompileCommand {
     input: PathBuf,
     output: PathBuf,
     root: Option<PathBuf>,
     watch: bool,
     font_paths: Vec<PathBuf>,
+    ppi: f32,
 }
 
 const HELP: &'static str = "\
@@ -52,7 +55,9 @@ USAGE:
 
 ARGS:
   <input.typ>    Path to input Typst file
-  [output.pdf]   Path to output PDF file
+  [output.pdf]   Path to output PDF or PNG file. To export a multi-page
+                 document to PNGs, use `{n}` in the file name, which is
+                 replaced by the page number (e.g. `page-{n}.png`)
 
 OPTIONS:
   -h, --help        Print this help
@@ -60,6 +65,7 @@ OPTIONS:
   -w, --watch       Watch the inputs and recompile on changes
   --font-path <dir> Add additional directories to search for fonts
   --root <dir>      Configure the root for absolute paths
+  --ppi <ppi>       Pixels per inch for PNG export [default: 144]
 
 SUBCOMMANDS:
   --fonts           List all discovered fonts in system and custom font paths
@@ -119,8 +125,16 @@ fn parse_args() -> StrResult<Command> {
 
         let root = args.opt_value_from_str("--root").map_err(|_| "missing root path")?;
         let watch = args.contains(["-w", "--watch"]);
+        let ppi = args
+            .opt_value_from_str("--ppi")
+            .map_err(|_| "invalid pixels per inch")?
+            .unwrap_or(144.0);
+        if ppi.is_nan() || ppi <= 0.0 {
+            Err("pixels per inch must be positive")?;
+        }
+
         let (input, output) = parse_input_output(&mut args, "pdf")?;
-        Command::Compile(CompileCommand { input, output, watch, root, font_paths })
+        Command::Compile(CompileCommand { input, output, watch, root, font_paths, ppi })
     };
 
     // Don't allow excess arguments.
@@ -251,10 +265,18 @@ fn compile_once(world: &mut SystemWorld, command: &CompileCommand) -> StrResult<
     world.main = world.resolve(&command.input).map_err(|err| err.to_string())?;
 
     match typst::compile(world) {
-        // Export the PDF.
+        // Export the PDF or PNGs.
         Ok(document) => {
-            let buffer = typst::export::pdf(&document);
-            fs::write(&command.output, buffer).map_err(|_| "failed to write PDF file")?;
+            match command.output.extension() {
+                Some(ext) if ext.eq_ignore_ascii_case("png") => {
+                    export_png(&document, command)?;
+                }
+                _ => {
+                    let buffer = typst::export::pdf(&document);
+                    fs::write(&command.output, buffer)
+                        .map_err(|_| "failed to write PDF file")?;
+                }
+            }
             status(command, Status::Success).unwrap();
         }
 
@@ -269,6 +291,44 @@ fn compile_once(world: &mut SystemWorld, command: &CompileCommand) -> StrResult<
     Ok(())
 }
 
+/// Export each page of the document into a PNG file.
+///
+/// If the output path contains `{n}`, it is replaced by the page number to
+/// obtain one file per page. Otherwise, the document must have exactly one page.
+/// The pixel density is configured with `--ppi`.
+fn export_png(document: &Document, command: &CompileCommand) -> StrResult<()> {
+    let template = command
+        .output
+        .to_str()
+        .ok_or("output path for PNG export must be valid UTF-8")?;
+    let numbered = template.contains("{n}");
+    if !numbered && document.pages.len() > 1 {
+        Err("cannot export multiple pages to PNG without `{n}` in the output path")?;
+    }
+
+    let count = document.pages.len();
+    for (i, frame) in document.pages.iter().enumerate() {
+        let pixmap = typst::export::render(frame, command.ppi / 72.0, Color::WHITE);
+        let path = if numbered {
+            page_path(template, i + 1, count)
+        } else {
+            command.output.clone()
+        };
+        pixmap.save_png(path).map_err(|_| "failed to write PNG file")?;
+    }
+
+    Ok(())
+}
+
+/// Replace `{n}` in an output path template by a page number.
+///
+/// The page numbers are padded so that the files sort correctly, e.g. the
+/// first of 100 pages is numbered "001".
+fn page_path(template: &str, number: usize, count: usize) -> PathBuf {
+    let width = count.to_string().len();
+    PathBuf::from(template.replace("{n}", &format!("{number:0width$}")))
+}
+
 /// Clear the terminal and render the status message.
 fn status(command: &CompileCommand, status: Status) -> io::Result<()> {
     if !command.watch {
@@ -734,3 +794,16 @@ impl FontSearcher {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_path() {
+        assert_eq!(page_path("page-{n}.png", 1, 1), Path::new("page-1.png"));
+        assert_eq!(page_path("page-{n}.png", 7, 12), Path::new("page-07.png"));
+        assert_eq!(page_path("page-{n}.png", 12, 12), Path::new("page-12.png"));
+        assert_eq!(page_path("{n}/{n}.png", 3, 100), Path::new("003/003.png"));
+    }
+}
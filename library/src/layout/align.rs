@@ -26,7 +26,9 @@ pub struct AlignElem {
     /// - `right`
     ///
     /// The `start` and `end` alignments are relative to the current [text
-    /// direction]($func/text.dir).
+    /// direction]($func/text.dir): In left-to-right text, `start` is the same
+    /// as `left`, while in right-to-left text, it is the same as `right`. All
+    /// other alignments refer to the physical edges, regardless of direction.
     ///
     /// Possible values for vertical alignments are:
     /// - `top`
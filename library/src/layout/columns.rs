@@ -4,10 +4,11 @@ use crate::text::TextElem;
 /// Separate a region into multiple equally sized columns.
 ///
 /// The `column` function allows to separate the interior of any container into
-/// multiple columns. It will not equalize the height of the columns, instead,
-/// the columns will take up the height of their container or the remaining
-/// height on the page. The columns function can break across pages if
-/// necessary.
+/// multiple columns. By default, it will not equalize the height of the
+/// columns, instead, the columns will take up the height of their container or
+/// the remaining height on the page. The columns function can break across
+/// pages if necessary. To equalize the columns in the last region, enable the
+/// [`balance`]($func/columns.balance) property.
 ///
 /// ## Example
 /// ```example
@@ -44,6 +45,26 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the columns in the last region.
+    ///
+    /// When enabled, the columns on the last page (or in the last region of
+    /// the container) are shortened such that they end at roughly the same
+    /// height. This requires the content to be layouted multiple times and thus
+    /// is off by default.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #columns(2, balance: true)[
+    ///   Columns that are balanced
+    ///   end up with roughly the
+    ///   same height even if the
+    ///   content is not enough to
+    ///   fill the whole page.
+    /// ]
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -76,7 +97,7 @@ impl Layout for ColumnsElem {
             .collect();
 
         // Create the pod regions.
-        let pod = Regions {
+        let mut pod = Regions {
             size: Size::new(width, regions.size.y),
             full: regions.full,
             backlog: &backlog,
@@ -84,6 +105,18 @@ impl Layout for ColumnsElem {
             expand: Axes::new(true, regions.expand.y),
         };
 
+        // Shorten the columns in the last region if requested.
+        let balanced = if self.balance(styles) {
+            balance(vt, styles, &body, pod, columns)?
+        } else {
+            None
+        };
+
+        if let Some(heights) = &balanced {
+            pod.size.y = heights[0];
+            pod.backlog = &heights[1..];
+        }
+
         // Layout the children.
         let mut frames = body.layout(vt, styles, pod)?.into_iter();
         let mut finished = vec![];
@@ -125,6 +158,90 @@ impl Layout for ColumnsElem {
     }
 }
 
+/// Shorten the columns in the last region such that the body still fits into
+/// the same number of columns, but these end at roughly the same height.
+///
+/// The height is found by binary search. Each step measures the body with the
+/// candidate height for the last region's columns and checks whether it still
+/// fits. Returns the column heights to use or `None` if nothing can be gained.
+fn balance(
+    vt: &mut Vt,
+    styles: StyleChain,
+    body: &Content,
+    pod: Regions,
+    columns: usize,
+) -> SourceResult<Option<Vec<Abs>>> {
+    // How precisely the balanced height is determined.
+    let tolerance = Abs::pt(0.5);
+
+    let mut measure = |heights: &[Abs]| {
+        let pod = Regions {
+            size: Size::new(pod.size.x, heights[0]),
+            backlog: &heights[1..],
+            ..pod
+        };
+        body.measure(vt, styles, pod)
+    };
+
+    // Find out which columns belong to the last region.
+    let mut heights: Vec<_> = std::iter::once(pod.size.y)
+        .chain(pod.backlog.iter().copied())
+        .collect();
+    let fragment = measure(&heights)?;
+    if fragment.len() == 0 {
+        return Ok(None);
+    }
+
+    let start = (fragment.len() - 1) / columns * columns;
+    let end = start + columns;
+    while heights.len() < end {
+        let Some(last) = pod.last else { return Ok(None) };
+        heights.push(last);
+    }
+
+    // In an unbounded region, the columns can't get longer than the
+    // content currently is.
+    let mut hi = heights[start];
+    if !hi.is_finite() {
+        hi = fragment
+            .iter()
+            .skip(start)
+            .map(Frame::height)
+            .fold(Abs::zero(), Abs::max);
+    }
+
+    let original = heights[start..end].to_vec();
+    let mut trial = heights;
+    let mut lo = Abs::zero();
+    let mut best = None;
+
+    while hi - lo > tolerance {
+        let mid = (lo + hi) / 2.0;
+        for (height, &prev) in trial[start..end].iter_mut().zip(&original) {
+            *height = prev.min(mid);
+        }
+
+        // The body fits if it needs no further columns and none of the columns
+        // overflows, e.g. because of an unbreakable block.
+        let fragment = measure(&trial)?;
+        let fits = fragment.len() <= end
+            && fragment
+                .iter()
+                .zip(&trial)
+                .skip(start)
+                .all(|(frame, &height)| height.fits(frame.height()));
+
+        if fits {
+            hi = mid;
+            best = Some(trial.clone());
+        } else {
+            lo = mid;
+        }
+    }
+
+    Ok(best)
+}
+
 /// A forced column break.
 ///
 /// The function will behave like a [page break]($func/pagebreak) when used in a
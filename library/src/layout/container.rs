@@ -40,6 +40,61 @@ pub struct BoxElem {
     /// The height of the box.
     pub height: Smart<Rel<Length>>,
 
+    /// The minimum width of the box.
+    ///
+    /// This only has an effect if the width is `{auto}`. The box then grows
+    /// to this width if its content is narrower. Relative bounds are ignored
+    /// if the surrounding container has an unbounded size.
+    ///
+    /// ```example
+    /// #for word in ("A", "Typesetting") {
+    ///   box(min-width: 40pt, fill: luma(230), word)
+    /// }
+    /// ```
+    #[resolve]
+    pub min_width: Option<Rel<Length>>,
+
+    /// The maximum width of the box.
+    ///
+    /// This only has an effect if the width is `{auto}`. The content is then
+    /// laid out with at most this width.
+    #[resolve]
+    pub max_width: Option<Rel<Length>>,
+
+    /// The minimum height of the box. Works like `min-width`.
+    #[resolve]
+    pub min_height: Option<Rel<Length>>,
+
+    /// The maximum height of the box. Works like `max-width`.
+    #[resolve]
+    pub max_height: Option<Rel<Length>>,
+
+    /// The ratio of the box's width to its height.
+    ///
+    /// If only one of `width` and `height` is given, the other one is derived
+    /// from it. If neither is given, the box grows along one axis until its
+    /// content fits with the desired ratio. This has no effect if both
+    /// dimensions are given.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 80pt,
+    ///   aspect: 16 / 9,
+    ///   fill: luma(230),
+    ///   align(center + horizon)[16:9],
+    /// )
+    /// ```
+    #[parse(
+        let aspect = args.named::<Spanned<Option<f64>>>("aspect")?;
+        if let Some(Spanned { v: Some(v), span }) = aspect {
+            if v <= 0.0 || !v.is_finite() {
+                bail!(span, "aspect ratio must be positive");
+            }
+        }
+        aspect.map(|aspect| aspect.v)
+    )]
+    pub aspect: Option<f64>,
+
     /// An amount to shift the box's baseline by.
     ///
     /// ```example
@@ -88,6 +143,23 @@ pub struct BoxElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the box.
+    ///
+    /// When enabled, content that overflows the box's size is cut off instead
+    /// of being painted over the surroundings.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 50pt,
+    ///   height: 20pt,
+    ///   clip: true,
+    ///   stroke: 1pt,
+    ///   lorem(20),
+    /// )
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The contents of the box.
     #[positional]
     pub body: Option<Content>,
@@ -108,13 +180,44 @@ impl Layout for BoxElem {
 
         // Resolve the sizing to a concrete size.
         let sizing = Axes::new(width, self.height(styles));
-        let expand = sizing.as_ref().map(Smart::is_custom);
-        let size = sizing
+        let mut expand = sizing.as_ref().map(Smart::is_custom);
+        let mut size = sizing
             .resolve(styles)
             .zip(regions.base())
             .map(|(s, b)| s.map(|v| v.relative_to(b)))
             .unwrap_or(regions.base());
 
+        // Resolve the size constraints of automatically sized axes. Relative
+        // constraints are ignored if there is no finite base to resolve them
+        // against.
+        let base = regions.base();
+        let bound = |v: Option<Rel<Abs>>, b: Abs| {
+            v.filter(|v| v.rel.is_zero() || b.is_finite())
+                .map(|v| v.relative_to(b))
+        };
+        let min = Axes::new(self.min_width(styles), self.min_height(styles))
+            .zip(base)
+            .map(|(v, b)| bound(v, b).unwrap_or(Abs::zero()));
+        let max = Axes::new(self.max_width(styles), self.max_height(styles))
+            .zip(base)
+            .map(|(v, b)| bound(v, b).unwrap_or(Abs::inf()));
+
+        // Derive a missing dimension from the aspect ratio.
+        let aspect = self.aspect(styles);
+        if let Some(aspect) = aspect {
+            match (expand.x, expand.y) {
+                (true, false) if size.x.is_finite() => {
+                    size.y = size.x / aspect;
+                    expand.y = true;
+                }
+                (false, true) if size.y.is_finite() => {
+                    size.x = size.y * aspect;
+                    expand.x = true;
+                }
+                _ => {}
+            }
+        }
+
         // Apply inset.
         let mut body = self.body(styles).unwrap_or_default();
         let inset = self.inset(styles);
@@ -124,15 +227,43 @@ impl Layout for BoxElem {
 
         // Select the appropriate base and expansion for the child depending
         // on whether it is automatically or relatively sized.
-        let pod = Regions::one(size, expand);
+        let limited = size
+            .zip(max)
+            .zip(expand)
+            .map(|((s, m), e)| if e { s } else { s.min(m) });
+        let pod = Regions::one(limited, expand);
         let mut frame = body.layout(vt, styles, pod)?.into_frame();
 
+        // Grow a box that fits its content to the aspect ratio.
+        if let Some(aspect) = aspect.filter(|_| !expand.x && !expand.y) {
+            let mut target = frame.size();
+            if target.x < target.y * aspect {
+                target.x = target.y * aspect;
+            } else {
+                target.y = target.x / aspect;
+            }
+            frame.resize(target, Align::LEFT_TOP);
+        }
+
+        // Clamp automatically sized axes to the size constraints.
+        let clamped = frame
+            .size()
+            .zip(min.zip(max))
+            .zip(expand)
+            .map(|((s, (min, max)), e)| if e { s } else { s.min(max).max(min) });
+        frame.resize(clamped, Align::LEFT_TOP);
+
         // Apply baseline shift.
         let shift = self.baseline(styles).relative_to(frame.height());
         if !shift.is_zero() {
             frame.set_baseline(frame.baseline() - shift);
         }
 
+        // Clip the contents.
+        if self.clip(styles) {
+            frame.clip();
+        }
+
         // Prepare fill and stroke.
         let fill = self.fill(styles);
         let stroke = self.stroke(styles).map(|s| s.map(PartialStroke::unwrap_or_default));
@@ -256,6 +387,11 @@ pub struct BlockElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the block. See the [box's
+    /// documentation]($func/box.clip) for more details.
+    #[default(false)]
+    pub clip: bool,
+
     /// The spacing around this block. This is shorthand to set `above` and
     /// `below` to the same value.
     ///
@@ -369,6 +505,13 @@ impl Layout for BlockElem {
             body.layout(vt, styles, pod)?.into_frames()
         };
 
+        // Clip the contents.
+        if self.clip(styles) {
+            for frame in &mut frames {
+                frame.clip();
+            }
+        }
+
         // Prepare fill and stroke.
         let fill = self.fill(styles);
         let stroke = self.stroke(styles).map(|s| s.map(PartialStroke::unwrap_or_default));
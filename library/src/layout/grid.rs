@@ -19,6 +19,10 @@ use super::Sizing;
 ///   as large as the remaining space. If there is more than one `{auto}` track
 ///   which, and together they claim more than the available space, the `{auto}`
 ///   tracks will fairly distribute the available space among themselves.
+///   Tracks whose contents fit into their fair share keep their natural size
+///   and only the remaining, wider tracks are shrunk, wrapping the contents of
+///   their cells. This way, a table with a mix of short and long cells sizes
+///   its columns to the content without overflowing the page.
 ///
 /// - A fixed or relative length (e.g. `{10pt}` or `{20% - 1cm}`): The track
 ///   will be exactly of this size.
@@ -96,7 +100,22 @@ pub struct GridElem {
 
     /// The contents of the table cells.
     ///
-    /// The cells are populated in row-major order.
+    /// The cells are populated in row-major order. If there are not enough
+    /// cells to fill the last row, its remaining cells stay empty. This makes
+    /// it easy to arrange a sequence of items into a fixed number of columns,
+    /// for instance for a calendar:
+    ///
+    /// ```example
+    /// #set align(right)
+    /// #grid(
+    ///   columns: 7,
+    ///   gutter: 6pt,
+    ///   ..("Mo", "Tu", "We", "Th", "Fr", "Sa", "Su")
+    ///     .map(day => strong(day)),
+    ///   ..range(3).map(i => []),
+    ///   ..range(1, 31).map(str),
+    /// )
+    /// ```
     #[variadic]
     pub children: Vec<Content>,
 }
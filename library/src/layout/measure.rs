@@ -29,7 +29,19 @@ use crate::prelude::*;
 /// ```
 ///
 /// The measure function returns a dictionary with the entries `width` and
-/// `height`, both of type [`length`]($type/length).
+/// `height`, both of type [`length`]($type/length). These can be used to size
+/// other content relative to the measured content, for example to draw a line
+/// that is exactly as wide as a title. To measure a labelled element from
+/// elsewhere in the document, first retrieve it with the
+/// [`query`]($func/query) function.
+///
+/// ```example
+/// #let title = [*Typst Quarterly*]
+/// #style(styles => {
+///   title
+///   line(length: measure(title, styles).width)
+/// })
+/// ```
 ///
 /// Display: Measure
 /// Category: layout
@@ -47,3 +59,62 @@ pub fn measure(
     let Size { x, y } = frame.size();
     dict! { "width" => x, "height" => y }.into()
 }
+
+/// Provides access to the space that is left in the current container.
+///
+/// When placed into a flow of blocks, the function receives the width and the
+/// height that remain in the current region, e.g. on the current page or in
+/// the current column. This makes it possible to fill the rest of a page with
+/// an element. If the container has no fixed height, for instance on a page
+/// with `{auto}` height, the remaining height is infinite.
+///
+/// ```example
+/// #set page(height: 120pt)
+/// Some text on top.
+///
+/// #layout(size => rect(
+///   width: 100%,
+///   height: size.height,
+///   fill: luma(230),
+/// ))
+/// ```
+///
+/// Display: Layout
+/// Category: layout
+/// Returns: content
+#[func]
+pub fn layout(
+    /// A function that receives a dictionary with the remaining `width` and
+    /// `height`. Its return value is displayed in the document.
+    ///
+    /// This function is called each time the content returned by `layout` is
+    /// laid out.
+    func: Func,
+) -> Value {
+    LayoutElem::new(func).pack().into()
+}
+
+/// Executes a `layout` call.
+///
+/// Display: Layout
+/// Category: special
+#[element(Layout)]
+struct LayoutElem {
+    /// The function to call with the remaining size.
+    #[required]
+    func: Func,
+}
+
+impl Layout for LayoutElem {
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let Size { x, y } = regions.size;
+        let size = dict! { "width" => x, "height" => y };
+        let result = self.func().call_vt(vt, [size.into()])?.display();
+        result.layout(vt, styles, regions)
+    }
+}
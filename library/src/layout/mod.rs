@@ -97,6 +97,12 @@ impl LayoutRoot for Content {
 }
 
 /// Layout into regions.
+///
+/// An element that is broken across multiple regions produces one frame per
+/// region it occupies, for example one per page or column. Things that should
+/// flow across lines, in contrast, do not need to be laid out as a unit: A
+/// function or show rule can simply return a sequence of inline content, whose
+/// pieces the surrounding paragraph then arranges individually.
 pub trait Layout {
     /// Layout into one frame per region.
     fn layout(
@@ -22,6 +22,23 @@ use crate::prelude::*;
 /// There you go, US friends!
 /// ```
 ///
+/// ## Special pages
+/// Title pages and colophons often look different from the body pages. To
+/// give such a page its own margins, header, or footer, call the page function
+/// directly with the properties for that page. The set rule for the body pages
+/// then follows it and applies to all later pages. If the pages only differ
+/// in their header or footer, you can instead decide what to show based on the
+/// [location]($func/locate) of the header.
+///
+/// ```example
+/// #page(margin: 1cm, header: none)[
+///   #align(center + horizon)[*Title*]
+/// ]
+///
+/// #set page(header: [_Body_])
+/// The body starts on page two.
+/// ```
+///
 /// Display: Page
 /// Category: layout
 #[element]
@@ -193,6 +210,11 @@ pub struct PageElem {
     ///
     /// #lorem(19)
     /// ```
+    ///
+    /// The header is considered to come before the body of its page. Hence,
+    /// when you [query]($func/query) for the headings before the header's
+    /// location, the last one is the heading of the section the page starts
+    /// in. This is useful to create running heads.
     pub header: Option<Content>,
 
     /// The amount the header is raised into the top margin.
@@ -1,6 +1,7 @@
 use typst::eval::Tracer;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::UnicodeSegmentation;
 use xi_unicode::LineBreakIterator;
 
 use super::{BoxElem, HElem, Sizing, Spacing};
@@ -51,6 +52,12 @@ pub struct ParElem {
     /// property hyphenate]($func/text.hyphenate) is set to `{auto}` and the
     /// current language is known.
     ///
+    /// Justification distributes the remaining space of each line among its
+    /// spaces. With [optimized line breaks]($func/par.linebreaks), spaces may
+    /// also be shrunk slightly to fit more text into a line. The last line of
+    /// a paragraph and lines ending in a regular line break are not stretched,
+    /// and neither is trailing whitespace at the end of a line.
+    ///
     /// Note that the current [alignment]($func/align) still has an effect on
     /// the placement of the last line except if it ends with a [justified line
     /// break]($func/linebreak.justify).
@@ -80,12 +87,19 @@ pub struct ParElem {
     /// very aesthetic example is one
     /// of them.
     /// ```
+    ///
+    /// In both cases, the possible break points are determined by the
+    /// [Unicode line breaking algorithm](https://www.unicode.org/reports/tr14/).
+    /// To allow a break where the algorithm doesn't, insert a zero-width space
+    /// (`{sym.zws}`). To prevent a break, insert a word joiner (`{sym.wj}`).
     #[default]
     pub linebreaks: Smart<Linebreaks>,
 
     /// The indent the first line of a consecutive paragraph should have.
     ///
-    /// The first paragraph on a page will never be indented.
+    /// The first paragraph on a page will never be indented. The same goes for
+    /// a paragraph directly following a heading or another block-level
+    /// element.
     ///
     /// By typographic convention, paragraph breaks are indicated by either some
     /// space between paragraphs or indented first lines. Consider turning the
@@ -95,9 +109,75 @@ pub struct ParElem {
     pub first_line_indent: Length,
 
     /// The indent all but the first line of a paragraph should have.
+    ///
+    /// The first line starts at the margin while all wrapped lines are
+    /// indented. This is commonly used for bibliographies, glossaries and verse.
+    ///
+    /// ```example
+    /// #set par(hanging-indent: 1em)
+    ///
+    /// Hypatia of Alexandria. _On the
+    /// Conics of Apollonius_. Alexandria,
+    /// around 400.
+    /// ```
     #[resolve]
     pub hanging_indent: Length,
 
+    /// The minimum number of lines a paragraph keeps together at its start.
+    ///
+    /// When a paragraph is broken across pages or columns, fewer lines than
+    /// this are never left behind at the bottom of the previous one. Instead,
+    /// they move along with the rest of the paragraph. Set this to `{1}` to
+    /// allow such lonely lines.
+    #[default(NonZeroUsize::new(2).unwrap())]
+    pub orphans: NonZeroUsize,
+
+    /// The minimum number of lines a paragraph keeps together at its end.
+    ///
+    /// When a paragraph is broken across pages or columns, it never ends with
+    /// fewer lines than this at the top of the next one. Set this to `{1}` to
+    /// allow such lonely lines.
+    #[default(NonZeroUsize::new(2).unwrap())]
+    pub widows: NonZeroUsize,
+
+    /// Whether to cut off text that does not fit into a single line.
+    ///
+    /// When enabled, the paragraph is not broken into multiple lines. Instead,
+    /// the text is cut off where it stops fitting and the
+    /// [ellipsis]($func/par.ellipsis) is inserted in its place. This is useful
+    /// for fixed-size containers like table cells. If
+    /// [`max-lines`]($func/par.max-lines) is set, the text is cut off in the
+    /// last permitted line instead.
+    ///
+    /// ```example
+    /// #set par(truncate: true)
+    /// #rect(width: 120pt, lorem(10))
+    /// ```
+    #[default(false)]
+    pub truncate: bool,
+
+    /// The text to insert where a [truncated]($func/par.truncate) paragraph is
+    /// cut off. If set to `{none}`, the text is cut off without a marker.
+    ///
+    /// ```example
+    /// #set par(truncate: true, ellipsis: " [...]")
+    /// #rect(width: 120pt, lorem(10))
+    /// ```
+    #[default(Some("…".into()))]
+    pub ellipsis: Option<EcoString>,
+
+    /// The maximum number of lines of the paragraph.
+    ///
+    /// Lines beyond the limit are dropped. To indicate that text was left out,
+    /// additionally enable [truncation]($func/par.truncate), which inserts an
+    /// ellipsis at the end of the last line.
+    ///
+    /// ```example
+    /// #set par(max-lines: 2, truncate: true)
+    /// #rect(width: 120pt, lorem(20))
+    /// ```
+    pub max_lines: Option<NonZeroUsize>,
+
     /// The contents of the paragraph.
     #[external]
     pub body: Content,
@@ -157,7 +237,11 @@ impl ParElem {
             let p = prepare(&mut vt, &children, &text, segments, spans, styles, region)?;
 
             // Break the paragraph into lines.
-            let lines = linebreak(&vt, &p, region.x - p.hang);
+            let mut lines = linebreak(&vt, &p, region.x - p.hang);
+
+            // Drop the lines that exceed the limit and cut off the text that
+            // doesn't fit if the paragraph is truncated.
+            truncate(&vt, &p, &mut lines, region.x - p.hang);
 
             // Stack the lines into one frame per region.
             finalize(&mut vt, &p, &lines, region, expand)
@@ -248,6 +332,8 @@ struct Preparation<'a> {
     justify: bool,
     /// The paragraph's hanging indent.
     hang: Abs,
+    /// The text to insert where the paragraph is truncated.
+    ellipsis: Option<EcoString>,
 }
 
 impl<'a> Preparation<'a> {
@@ -423,6 +509,8 @@ struct Line<'a> {
     /// Whether the line ends with a hyphen or dash, either naturally or through
     /// hyphenation.
     dash: bool,
+    /// The shaped ellipsis if the paragraph was truncated after this line.
+    ellipsis: Option<Item<'a>>,
 }
 
 impl<'a> Line<'a> {
@@ -540,7 +628,8 @@ fn collect<'a>(
         } else if let Some(elem) = child.to::<TextElem>() {
             let prev = full.len();
             if let Some(case) = TextElem::case_in(styles) {
-                full.push_str(&case.apply(&elem.text()));
+                let lang = TextElem::lang_in(styles);
+                full.push_str(&case.apply(&elem.text(), lang, full.chars().last()));
             } else {
                 full.push_str(&elem.text());
             }
@@ -685,6 +774,7 @@ fn prepare<'a>(
         align: AlignElem::alignment_in(styles).x.resolve(styles),
         justify: ParElem::justify_in(styles),
         hang: ParElem::hanging_indent_in(styles),
+        ellipsis: ParElem::ellipsis_in(styles),
     })
 }
 
@@ -1063,6 +1153,7 @@ fn line<'a>(
             width: Abs::zero(),
             justify,
             dash: false,
+            ellipsis: None,
         };
     }
 
@@ -1146,7 +1237,84 @@ fn line<'a>(
         width,
         justify,
         dash,
+        ellipsis: None,
+    }
+}
+
+/// Drop the lines exceeding the paragraph's line limit. If the paragraph is
+/// truncated, additionally cut off the text where it stops fitting into the
+/// last remaining line and append the ellipsis.
+fn truncate<'a>(vt: &Vt, p: &'a Preparation<'a>, lines: &mut Vec<Line<'a>>, width: Abs) {
+    let truncate = ParElem::truncate_in(p.styles);
+    let limit = match ParElem::max_lines_in(p.styles) {
+        Some(max) => max.get(),
+        None if truncate => 1,
+        None => return,
+    };
+
+    if !truncate {
+        if lines.len() > limit {
+            // The last remaining line now ends the paragraph, so it isn't
+            // justified.
+            lines.truncate(limit);
+            lines[limit - 1].justify = false;
+        }
+        return;
+    }
+
+    if lines.len() < limit || (lines.len() == limit && width.fits(lines[limit - 1].width))
+    {
+        return;
+    }
+
+    // The text of the last remaining line may extend up to the end of the
+    // following line, unless it is terminated by a forced line break.
+    let last = &lines[limit - 1];
+    let start = last.trimmed.start;
+    let forced = p.bidi.text[last.trimmed.end..last.end]
+        .contains(['\n', '\r', '\u{2028}', '\u{2029}']);
+    let bound = if lines.len() == limit || forced { last.end } else { lines[limit].end };
+
+    // Shape the ellipsis with the styles of the text it replaces.
+    let ellipsis = p.ellipsis.as_deref().map(|ellipsis| {
+        let styles = p
+            .find(bound.saturating_sub(1))
+            .and_then(Item::text)
+            .map_or(p.styles, |shaped| shaped.styles);
+        let dir = TextElem::dir_in(styles);
+        shape(vt, bound, ellipsis, &p.spans, styles, dir)
+    });
+
+    // Find the longest line that still fits together with the ellipsis. We
+    // only cut between grapheme clusters.
+    let available = width - ellipsis.as_ref().map_or(Abs::zero(), |shaped| shaped.width);
+    let ends: Vec<_> = p.bidi.text[start..bound]
+        .grapheme_indices(true)
+        .map(|(i, _)| start + i)
+        .chain(std::iter::once(bound))
+        .collect();
+
+    let mut lo = 0;
+    let mut hi = ends.len() - 1;
+    while lo < hi {
+        let mid = (lo + hi + 1) / 2;
+        if available.fits(line(vt, p, start..ends[mid], true, false).width) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    // The truncated line ends the paragraph, so it is built like a line with a
+    // mandatory break and isn't justified.
+    let mut truncated = line(vt, p, start..ends[lo], true, false);
+    if let Some(shaped) = ellipsis {
+        truncated.width += shaped.width;
+        truncated.ellipsis = Some(Item::Text(shaped));
     }
+
+    lines.truncate(limit - 1);
+    lines.push(truncated);
 }
 
 /// Combine layouted lines into one frame per region.
@@ -1175,15 +1343,21 @@ fn finalize(
 
     // Prevent orphans.
     let leading = ParElem::leading_in(p.styles);
-    if frames.len() >= 2 && !frames[1].is_empty() {
+    for _ in 1..ParElem::orphans_in(p.styles).get() {
+        if frames.len() < 2 || frames[1].is_empty() {
+            break;
+        }
         let second = frames.remove(1);
         let first = &mut frames[0];
         merge(first, second, leading);
     }
 
     // Prevent widows.
-    let len = frames.len();
-    if len >= 2 && !frames[len - 2].is_empty() {
+    for _ in 1..ParElem::widows_in(p.styles).get() {
+        let len = frames.len();
+        if len < 2 || frames[len - 2].is_empty() {
+            break;
+        }
         let second = frames.pop().unwrap();
         let first = frames.last_mut().unwrap();
         merge(first, second, leading);
@@ -1212,11 +1386,20 @@ fn commit(
     let mut offset = Abs::zero();
 
     // Reorder the line from logical to visual order.
-    let (reordered, starts_rtl) = reorder(line);
+    let (mut reordered, starts_rtl) = reorder(line);
     if !starts_rtl {
         offset += p.hang;
     }
 
+    // Insert the ellipsis of a truncated line at its logical end.
+    if let Some(ellipsis) = &line.ellipsis {
+        if TextElem::dir_in(p.styles).is_positive() {
+            reordered.push(ellipsis);
+        } else {
+            reordered.insert(0, ellipsis);
+        }
+    }
+
     // Handle hanging punctuation to the left.
     if let Some(Item::Text(text)) = reordered.first() {
         if let Some(glyph) = text.glyphs.first() {
@@ -1295,6 +1478,18 @@ fn commit(
         }
     }
 
+    // Mark a forced line break at the line's logical end. The marker extends
+    // past the text, so it doesn't affect the layout.
+    if let Some(marker) = break_marker(vt, p, line) {
+        let marker = marker.build(vt, Abs::zero());
+        let x = if TextElem::dir_in(p.styles).is_positive() {
+            offset
+        } else {
+            frames.first().map_or(offset, |(x, _)| *x) - marker.width()
+        };
+        frames.push((x, marker));
+    }
+
     // Remaining space is distributed now.
     if !fr.is_zero() {
         remaining = Abs::zero();
@@ -1314,6 +1509,26 @@ fn commit(
     Ok(output)
 }
 
+/// Shape the marker for a forced line break at the end of the line if
+/// whitespace is visible.
+fn break_marker<'a>(
+    vt: &Vt,
+    p: &'a Preparation<'a>,
+    line: &Line,
+) -> Option<ShapedText<'a>> {
+    let tail = &p.bidi.text[line.trimmed.end..line.end];
+    let offset = line.trimmed.end + tail.find(['\n', '\r', '\u{2028}', '\u{2029}'])?;
+    let styles = p
+        .find(offset)
+        .and_then(Item::text)
+        .map_or(p.styles, |shaped| shaped.styles);
+    if !TextElem::visible_whitespace_in(styles) {
+        return None;
+    }
+
+    Some(shape(vt, offset, "¶", &p.spans, styles, TextElem::dir_in(styles)))
+}
+
 /// Return a line's items in visual order.
 fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&Item<'a>>, bool) {
     let mut reordered = vec![];
@@ -31,6 +31,26 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// Whether to increase the space between instances to completely fill the
+    /// available space.
+    ///
+    /// When this is disabled, the instances are placed directly next to each
+    /// other and the remaining space is distributed according to the
+    /// [alignment]($func/align.alignment). Aligning the instances at the end
+    /// makes dot leaders line up across multiple lines as long as the content
+    /// after them has the same width, for example with
+    /// [tabular numbers]($func/text.number-width).
+    ///
+    /// ```example
+    /// #set text(number-width: "tabular")
+    /// #let leader = align(end, repeat(justify: false)[.])
+    /// Intro #box(width: 1fr, leader) 1 \
+    /// Methods and results #box(width: 1fr, leader) 7 \
+    /// Discussion #box(width: 1fr, leader) 13
+    /// ```
+    #[default(true)]
+    pub justify: bool,
 }
 
 impl Layout for RepeatElem {
@@ -48,7 +68,8 @@ impl Layout for RepeatElem {
         let width = piece.width();
         let count = (fill / width).floor();
         let remaining = fill % width;
-        let apart = remaining / (count - 1.0);
+        let justify = self.justify(styles);
+        let apart = if justify { remaining / (count - 1.0) } else { Abs::zero() };
 
         let size = Size::new(regions.size.x, piece.height());
 
@@ -62,7 +83,7 @@ impl Layout for RepeatElem {
         }
 
         let mut offset = Abs::zero();
-        if count == 1.0 {
+        if count == 1.0 || !justify {
             offset += align.position(remaining);
         }
 
@@ -29,7 +29,8 @@ pub struct HElem {
 
     /// If true, the spacing collapses at the start or end of a paragraph.
     /// Moreover, from multiple adjacent weak spacings all but the largest one
-    /// collapse.
+    /// collapse. Non-weak spacings, in contrast, never collapse: Multiple
+    /// adjacent ones add up.
     ///
     /// ```example
     /// #h(1cm, weak: true)
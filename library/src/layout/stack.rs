@@ -30,6 +30,11 @@ pub struct StackElem {
     pub dir: Dir,
 
     /// Spacing to insert between items where no explicit spacing was provided.
+    ///
+    /// Spacing, whether explicit or not, is always inserted along the stack's
+    /// direction. To arrange items along both axes, nest stacks with different
+    /// directions: Each of them only accounts for the spacing among its own
+    /// children.
     pub spacing: Option<Spacing>,
 
     /// The childfren to stack along the axis.
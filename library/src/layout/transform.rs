@@ -52,10 +52,10 @@ impl Layout for MoveElem {
     }
 }
 
-/// Rotate content with affecting layout.
+/// Rotate content without affecting layout.
 ///
 /// Rotate an element by a given angle. The layout will act as if the element
-/// was not rotated.
+/// was not rotated, unless [`reflow`]($func/rotate.reflow) is enabled.
 ///
 /// ## Example
 /// ```example
@@ -99,6 +99,19 @@ pub struct RotateElem {
     #[resolve]
     pub origin: Axes<Option<GenAlign>>,
 
+    /// Whether the rotation impacts the layout.
+    ///
+    /// If set to `{false}`, the rotated content retains the bounding box of
+    /// the original content. If set to `{true}`, the bounding box is adjusted
+    /// to fit the rotated content, so that surrounding content makes room for
+    /// it.
+    ///
+    /// ```example
+    /// Hello #box(rotate(90deg, reflow: true)[World])!
+    /// ```
+    #[default(false)]
+    pub reflow: bool,
+
     /// The content to rotate.
     #[required]
     pub body: Content,
@@ -118,7 +131,11 @@ impl Layout for RotateElem {
         let ts = Transform::translate(x, y)
             .pre_concat(Transform::rotate(self.angle(styles)))
             .pre_concat(Transform::translate(-x, -y));
-        frame.transform(ts);
+        if self.reflow(styles) {
+            reflow(&mut frame, ts);
+        } else {
+            frame.transform(ts);
+        }
         Ok(Fragment::frame(frame))
     }
 }
@@ -126,8 +143,13 @@ impl Layout for RotateElem {
 /// Scale content without affecting layout.
 ///
 /// The `scale` function allows you to scale and mirror content without
-/// affecting the layout.
+/// affecting the layout, unless [`reflow`]($func/scale.reflow) is enabled.
 ///
+/// Scaling is a pure transformation: The content is layouted at its original
+/// size and only then scaled. Text is thus not layouted again at the scaled
+/// size. To enlarge the content of a whole document, change the
+/// [text size]($func/text.size) instead. Since most default lengths are
+/// specified relative to the font size, the rest of the layout scales along.
 ///
 /// ## Example
 /// ```example
@@ -167,6 +189,18 @@ pub struct ScaleElem {
     #[resolve]
     pub origin: Axes<Option<GenAlign>>,
 
+    /// Whether the scaling impacts the layout.
+    ///
+    /// If set to `{false}`, the scaled content retains the bounding box of the
+    /// original content. If set to `{true}`, the bounding box is adjusted to
+    /// fit the scaled content, so that surrounding content moves along.
+    ///
+    /// ```example
+    /// Hello #box(scale(x: 200%, reflow: true)[World])!
+    /// ```
+    #[default(false)]
+    pub reflow: bool,
+
     /// The content to scale.
     #[required]
     pub body: Content,
@@ -186,7 +220,46 @@ impl Layout for ScaleElem {
         let transform = Transform::translate(x, y)
             .pre_concat(Transform::scale(self.x(styles), self.y(styles)))
             .pre_concat(Transform::translate(-x, -y));
-        frame.transform(transform);
+        if self.reflow(styles) {
+            reflow(&mut frame, transform);
+        } else {
+            frame.transform(transform);
+        }
         Ok(Fragment::frame(frame))
     }
 }
+
+/// Transform a frame and resize it to the bounding box of the transformed
+/// content.
+///
+/// The baseline is transformed along if it stays horizontal, that is, when
+/// the content is only scaled, mirrored or turned upside down. Otherwise,
+/// the frame's bottom edge becomes its baseline.
+fn reflow(frame: &mut Frame, ts: Transform) {
+    let size = frame.size();
+    let mut corners =
+        [Point::zero(), Point::with_x(size.x), Point::with_y(size.y), size.to_point()]
+            .into_iter()
+            .map(|corner| corner.transform(ts));
+
+    let first = corners.next().unwrap();
+    let (min, max) = corners
+        .fold((first, first), |(min, max), corner| (min.min(corner), max.max(corner)));
+
+    let baseline = frame.has_baseline().then(|| {
+        let start = Point::with_y(frame.baseline()).transform(ts);
+        let end = Point::new(size.x, frame.baseline()).transform(ts);
+        if start.y.approx_eq(end.y) {
+            start.y - min.y
+        } else {
+            max.y - min.y
+        }
+    });
+
+    frame.transform(ts);
+    frame.translate(-min);
+    frame.set_size((max - min).to_size());
+    if let Some(baseline) = baseline {
+        frame.set_baseline(baseline);
+    }
+}
@@ -35,9 +35,11 @@ fn global(math: Module, calc: Module) -> Module {
     global.define("smartquote", text::SmartQuoteElem::func());
     global.define("strong", text::StrongElem::func());
     global.define("emph", text::EmphElem::func());
+    global.define("isolate", text::IsolateElem::func());
     global.define("lower", text::lower);
     global.define("upper", text::upper);
-    global.define("smallcaps", text::smallcaps);
+    global.define("titlecase", text::titlecase);
+    global.define("smallcaps", text::SmallcapsElem::func());
     global.define("sub", text::SubElem::func());
     global.define("super", text::SuperElem::func());
     global.define("underline", text::UnderlineElem::func());
@@ -75,6 +77,7 @@ fn global(math: Module, calc: Module) -> Module {
     global.define("rotate", layout::RotateElem::func());
     global.define("hide", layout::HideElem::func());
     global.define("measure", layout::measure);
+    global.define("layout", layout::layout);
 
     // Visualize.
     global.define("image", visualize::ImageElem::func());
@@ -83,6 +86,7 @@ fn global(math: Module, calc: Module) -> Module {
     global.define("square", visualize::SquareElem::func());
     global.define("ellipse", visualize::EllipseElem::func());
     global.define("circle", visualize::CircleElem::func());
+    global.define("polygon", visualize::PolygonElem::func());
 
     // Meta.
     global.define("document", meta::DocumentElem::func());
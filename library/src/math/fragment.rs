@@ -220,6 +220,7 @@ impl GlyphFragment {
             size: self.font_size,
             fill: self.fill,
             lang: self.lang,
+            dir: Dir::LTR,
             glyphs: vec![Glyph {
                 id: self.id.0,
                 c: self.c,
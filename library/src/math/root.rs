@@ -121,8 +121,11 @@ fn layout(
     frame.push(
         line_pos,
         FrameItem::Shape(
-            Geometry::Line(Point::with_x(radicand.width()))
-                .stroked(Stroke { paint: TextElem::fill_in(ctx.styles()), thickness }),
+            Geometry::Line(Point::with_x(radicand.width())).stroked(Stroke {
+                paint: TextElem::fill_in(ctx.styles()),
+                thickness,
+                dash: None,
+            }),
             span,
         ),
     );
@@ -296,7 +296,7 @@ impl NumberingKind {
                     let c = b'a' + (n % 26) as u8;
                     letters.push(match case {
                         Case::Lower => c,
-                        Case::Upper => c.to_ascii_uppercase(),
+                        Case::Upper | Case::Title => c.to_ascii_uppercase(),
                     });
                     n /= 26;
                     if n == 0 {
@@ -338,7 +338,7 @@ impl NumberingKind {
                         for c in name.chars() {
                             match case {
                                 Case::Lower => fmt.extend(c.to_lowercase()),
-                                Case::Upper => fmt.push(c),
+                                Case::Upper | Case::Title => fmt.push(c),
                             }
                         }
                     }
@@ -271,6 +271,7 @@ pub(super) fn decorate(
     let stroke = deco.stroke.unwrap_or(Stroke {
         paint: text.fill,
         thickness: metrics.thickness.at(text.size),
+        dash: None,
     });
 
     let gap_padding = 0.08 * text.size;
@@ -1,4 +1,6 @@
-use super::TextElem;
+use typst::doc::Lang;
+
+use super::{has_feature, variant, TextDir, TextElem, TextSize};
 use crate::prelude::*;
 
 /// A text space.
@@ -20,7 +22,9 @@ impl Unlabellable for SpaceElem {}
 ///
 /// Advances the paragraph to the next line. A single trailing line break at the
 /// end of a paragraph is ignored, but more than one creates additional empty
-/// lines.
+/// lines. An empty line takes up as much vertical space as a line of text: Its
+/// height and baseline are derived from the metrics of the current font and
+/// the [top]($func/text.top-edge) and [bottom]($func/text.bottom-edge) edges.
 ///
 /// ## Example
 /// ```example
@@ -185,6 +189,49 @@ impl Fold for Toggle {
     }
 }
 
+/// Isolates content from the surrounding text's direction.
+///
+/// When embedding text of unknown direction (e.g. a user name or a file name)
+/// into a paragraph, its characters can alter the order of the surrounding
+/// text. For instance, a name written in a right-to-left script followed by
+/// punctuation may cause the punctuation to move to the wrong side. Isolating
+/// the content makes sure that it is ordered independently from its
+/// surroundings, which are in turn not affected by it.
+///
+/// ## Example
+/// ```example
+/// The file #isolate[مرحبا.txt] is missing. \
+/// The user #isolate(dir: rtl)[Alice!] left.
+/// ```
+///
+/// Display: Isolate
+/// Category: text
+#[element(Show)]
+pub struct IsolateElem {
+    /// The direction of the isolated content.
+    ///
+    /// When set to `{auto}`, the direction is determined from the first
+    /// strongly directional character of the content.
+    pub dir: TextDir,
+
+    /// The content to isolate.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for IsolateElem {
+    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        // Enclose the body in the Unicode isolate control characters.
+        let start = match self.dir(styles).0 {
+            Smart::Auto => "\u{2068}",
+            Smart::Custom(Dir::RTL) => "\u{2067}",
+            Smart::Custom(_) => "\u{2066}",
+        };
+
+        Ok(TextElem::packed(start) + self.body() + TextElem::packed("\u{2069}"))
+    }
+}
+
 /// Convert text or content to lowercase.
 ///
 /// ## Example
@@ -225,10 +272,32 @@ pub fn upper(
     case(text, Case::Upper)
 }
 
+/// Convert text or content to title case.
+///
+/// The first letter of each word is uppercased, all other letters are left
+/// unchanged.
+///
+/// ## Example
+/// ```example
+/// #titlecase("the quick fox") \
+/// #titlecase[*an ode* to joy]
+/// ```
+///
+/// Display: Title Case
+/// Category: text
+/// Returns: string or content
+#[func]
+pub fn titlecase(
+    /// The text to convert to title case.
+    text: ToCase,
+) -> Value {
+    case(text, Case::Title)
+}
+
 /// Change the case of text.
 fn case(text: ToCase, case: Case) -> Value {
     match text {
-        ToCase::Str(v) => Value::Str(case.apply(&v).into()),
+        ToCase::Str(v) => Value::Str(case.apply(&v, Lang::ENGLISH, None).into()),
         ToCase::Content(v) => Value::Content(v.styled(TextElem::set_case(Some(case)))),
     }
 }
@@ -252,25 +321,75 @@ pub enum Case {
     Lower,
     /// Everything is uppercased.
     Upper,
+    /// The first letter of each word is uppercased.
+    Title,
 }
 
 impl Case {
     /// Apply the case to a string.
-    pub fn apply(self, text: &str) -> String {
+    ///
+    /// The `lang` selects language-specific mappings, like the dotted and
+    /// dotless i in Turkish. The `prev` character is the one preceding the
+    /// text, which determines whether the text starts a new word.
+    pub fn apply(self, text: &str, lang: Lang, prev: Option<char>) -> String {
+        let turkic = matches!(lang.as_str(), "tr" | "az");
         match self {
-            Self::Lower => text.to_lowercase(),
-            Self::Upper => text.to_uppercase(),
+            Self::Lower => lower_str(text, turkic),
+            Self::Upper => upper_str(text, turkic),
+            Self::Title => {
+                let mut prev = prev;
+                let mut out = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if prev.map_or(true, |p| !is_word_char(p)) {
+                        out.push_str(&upper_str(c.encode_utf8(&mut [0; 4]), turkic));
+                    } else {
+                        out.push(c);
+                    }
+                    prev = Some(c);
+                }
+                out
+            }
         }
     }
 }
 
+/// Lowercase a string.
+///
+/// Apart from the Turkic dotted and dotless i, this uses the standard
+/// mapping, which handles context-sensitive cases like the Greek final sigma.
+fn lower_str(text: &str, turkic: bool) -> String {
+    if turkic {
+        text.replace('I', "ı").replace('İ', "i").to_lowercase()
+    } else {
+        text.to_lowercase()
+    }
+}
+
+/// Uppercase a string.
+fn upper_str(text: &str, turkic: bool) -> String {
+    if turkic {
+        text.replace('i', "İ").to_uppercase()
+    } else {
+        text.to_uppercase()
+    }
+}
+
+/// Whether a character continues a word for the purpose of title casing.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '\'' | '’')
+}
+
 /// Display text in small capitals.
 ///
-/// _Note:_ This enables the OpenType `smcp` feature for the font. Not all fonts
-/// support this feature. Sometimes smallcaps are part of a dedicated font and
-/// sometimes they are not available at all. In the future, this function will
-/// support selecting a dedicated smallcaps font as well as synthesizing
-/// smallcaps from normal letters, but this is not yet implemented.
+/// This enables the OpenType `smcp` feature for the font. If the font doesn't
+/// support this feature, small capitals are synthesized by setting lowercase
+/// letters as scaled-down uppercase letters. Synthesis only works if the body
+/// consists of plain text and spaces. Whether to synthesize is decided by the
+/// first font in the [font list]($func/text.font) that is available. Fonts that
+/// are only used as a fallback for glyphs this font lacks are not considered.
+/// Sometimes smallcaps are part of a
+/// dedicated font. In the future, this function will support selecting such a
+/// font, but this is not yet implemented.
 ///
 /// ## Example
 /// ```example
@@ -289,13 +408,81 @@ impl Case {
 ///
 /// Display: Small Capitals
 /// Category: text
-/// Returns: content
-#[func]
-pub fn smallcaps(
+#[element(Show)]
+pub struct SmallcapsElem {
     /// The text to display to small capitals.
-    body: Content,
-) -> Value {
-    Value::Content(body.styled(TextElem::set_smallcaps(true)))
+    #[required]
+    pub body: Content,
+}
+
+impl Show for SmallcapsElem {
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let body = self.body();
+        if !has_smallcaps(vt, styles) {
+            let lang = TextElem::lang_in(styles);
+            if let Some(synthesized) = synthesize_smallcaps(&body, lang) {
+                return Ok(synthesized);
+            }
+        }
+
+        Ok(body.styled(TextElem::set_smallcaps(true)))
+    }
+}
+
+/// Checks whether the first retrievable family has small capitals.
+///
+/// This doesn't consider fallback fonts, which are only known once the text
+/// is shaped.
+fn has_smallcaps(vt: &Vt, styles: StyleChain) -> bool {
+    let world = vt.world;
+    for family in TextElem::font_in(styles) {
+        if let Some(font) = world
+            .book()
+            .select(family.as_str(), variant(styles))
+            .and_then(|id| world.font(id))
+        {
+            return has_feature(&font, ttf_parser::Tag::from_bytes(b"smcp"));
+        }
+    }
+
+    false
+}
+
+/// Synthesize small capitals for content consisting only of `Text` and
+/// `Space` leafs. The `lang` selects the case mapping, like in `upper`.
+fn synthesize_smallcaps(content: &Content, lang: Lang) -> Option<Content> {
+    if content.is::<SpaceElem>() {
+        Some(content.clone())
+    } else if let Some(elem) = content.to::<TextElem>() {
+        let text = elem.text();
+        let span = elem.span();
+        let mut pieces = vec![];
+        let mut start = 0;
+        for (i, c) in text.char_indices() {
+            let next = text[i + c.len_utf8()..].chars().next();
+            if next.map_or(true, |n| n.is_lowercase() != c.is_lowercase()) {
+                let end = i + c.len_utf8();
+                let piece = &text[start..end];
+                pieces.push(if c.is_lowercase() {
+                    TextElem::packed(Case::Upper.apply(piece, lang, None))
+                        .spanned(span)
+                        .styled(TextElem::set_size(TextSize(Em::new(0.75).into())))
+                } else {
+                    TextElem::packed(piece).spanned(span)
+                });
+                start = end;
+            }
+        }
+        Some(Content::sequence(pieces))
+    } else if let Some(children) = content.to_sequence() {
+        let mut pieces = vec![];
+        for child in children {
+            pieces.push(synthesize_smallcaps(child, lang)?);
+        }
+        Some(Content::sequence(pieces))
+    } else {
+        None
+    }
 }
 
 /// Create blind text.
@@ -18,6 +18,7 @@ use std::borrow::Cow;
 
 use rustybuzz::Tag;
 use typst::font::{FontMetrics, FontStretch, FontStyle, FontWeight, VerticalFontMetric};
+use unicode_script::Script;
 
 use crate::layout::ParElem;
 use crate::prelude::*;
@@ -82,6 +83,28 @@ pub struct TextElem {
     #[default(true)]
     pub fallback: bool,
 
+    /// Font families to prefer for text in specific scripts.
+    ///
+    /// Maps [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) script codes
+    /// like `latn`, `hani` or `arab` to font families. When shaping a run of
+    /// text in one of these scripts, Typst first tries the designated families
+    /// and only then falls back to the general [font list]($func/text.font).
+    /// This is more predictable than a single fallback list when you want to
+    /// pair fonts for multilingual documents. Setting this multiple times
+    /// merges the mappings, with the innermost set rule taking precedence.
+    ///
+    /// ```example
+    /// #set text(script-fonts: (
+    ///   latn: "Inria Serif",
+    ///   arab: "Noto Sans Arabic",
+    /// ))
+    ///
+    /// This is Latin. \
+    /// هذا عربي.
+    /// ```
+    #[fold]
+    pub script_fonts: ScriptFonts,
+
     /// The desired font style.
     ///
     /// When an italic style is requested and only an oblique one is available,
@@ -157,6 +180,10 @@ pub struct TextElem {
 
     /// The amount of space that should be added between characters.
     ///
+    /// The space is only inserted between characters, so that lines start and
+    /// end flush with their edges. It is not stretched when the paragraph is
+    /// justified.
+    ///
     /// ```example
     /// #set text(tracking: 1.5pt)
     /// Distant text.
@@ -281,6 +308,12 @@ pub struct TextElem {
     /// values `start` and `end`, which are equivalent to `left` and `right` in
     /// `ltr` text and the other way around in `rtl` text.
     ///
+    /// Within right-to-left runs, mirrorable characters like parentheses,
+    /// brackets and relational symbols are automatically displayed in their
+    /// mirrored form during shaping, as required by the Unicode bidirectional
+    /// algorithm. An opening parenthesis thus always opens towards the text it
+    /// encloses.
+    ///
     /// If you set this to `rtl` and experience bugs or in some way bad looking
     /// output, please do get in touch with us through the
     /// [contact form](https://typst.app/contact) or our
@@ -297,7 +330,14 @@ pub struct TextElem {
     /// will be hyphenated if and only if justification is enabled.
     ///
     /// Setting the [text language]($func/text.lang) ensures that the correct
-    /// hyphenation patterns are used.
+    /// hyphenation patterns are used. Words are hyphenated with the Knuth-Liang
+    /// patterns of the language, which also define how many letters must at
+    /// least remain before and after a hyphen at the start and end of a word.
+    ///
+    /// Independently of this setting, you can mark hyphenation opportunities
+    /// manually with soft hyphens, written as `-?` in markup or `\u{ad}` in
+    /// strings. A soft hyphen is invisible unless the line is broken at it, in
+    /// which case a hyphen is shown at the end of the line.
     ///
     /// ```example
     /// #set par(justify: true)
@@ -397,6 +437,11 @@ pub struct TextElem {
     /// The width of numbers / figures. When set to `{auto}`, the default
     /// numbers for the font are used.
     ///
+    /// Tabular numbers are selected through the font's `tnum` feature. For
+    /// fonts that lack this feature, the digits are additionally centered in
+    /// the width of the font's widest digit, so that they line up in tables
+    /// either way. All other glyphs keep their natural width.
+    ///
     /// ```example
     /// #set text(font: "Noto Sans", 20pt)
     /// #set text(number-width: "proportional")
@@ -443,6 +488,26 @@ pub struct TextElem {
     #[fold]
     pub features: FontFeatures,
 
+    /// Whether to make whitespace visible.
+    ///
+    /// This is a debugging aid for tracking down stray or unexpected
+    /// whitespace: Spaces are drawn as `·`, tabs as `→` and forced line
+    /// breaks as `¶`. Only the drawn glyphs are substituted and the line break
+    /// marker extends past the line, so the layout stays exactly the same.
+    /// Other invisible characters like zero-width spaces or joiners are not
+    /// marked.
+    ///
+    /// The markers are real glyphs, so text copied from an exported PDF
+    /// contains them in place of the whitespace.
+    ///
+    /// ```example
+    /// #set text(visible-whitespace: true)
+    /// Where are  the spaces? \
+    /// Here!
+    /// ```
+    #[default(false)]
+    pub visible_whitespace: bool,
+
     /// Content in which all text is styled according to the other arguments.
     #[external]
     #[required]
@@ -551,6 +616,52 @@ cast_to_value! {
     v: FontList => v.0.into()
 }
 
+/// Font families designated for specific scripts.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ScriptFonts(pub Vec<(Script, FontList)>);
+
+impl ScriptFonts {
+    /// The font families designated for the given script, if any.
+    pub fn get(&self, script: Script) -> Option<&FontList> {
+        self.0.iter().find(|(s, _)| *s == script).map(|(_, list)| list)
+    }
+}
+
+cast_from_value! {
+    ScriptFonts,
+    values: Dict => Self(values
+        .into_iter()
+        .map(|(k, v)| {
+            let mut code = k.to_lowercase();
+            if let Some(first) = code.get_mut(..1) {
+                first.make_ascii_uppercase();
+            }
+            let script = Script::from_short_name(&code)
+                .ok_or("expected ISO 15924 script code")?;
+            Ok((script, v.cast::<FontList>()?))
+        })
+        .collect::<StrResult<_>>()?),
+}
+
+cast_to_value! {
+    v: ScriptFonts => Value::Dict(
+        v.0.into_iter()
+            .map(|(script, list)| {
+                (script.short_name().to_lowercase().into(), list.into())
+            })
+            .collect(),
+    )
+}
+
+impl Fold for ScriptFonts {
+    type Output = Self;
+
+    fn fold(mut self, outer: Self::Output) -> Self::Output {
+        self.0.extend(outer.0);
+        self
+    }
+}
+
 /// The size of text.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TextSize(pub Length);
@@ -4,6 +4,7 @@ use std::str::FromStr;
 use rustybuzz::{Feature, Tag, UnicodeBuffer};
 use typst::font::{Font, FontVariant};
 use typst::util::SliceExt;
+use unicode_script::{Script, UnicodeScript};
 
 use super::*;
 use crate::layout::SpanMapper;
@@ -65,7 +66,7 @@ pub struct ShapedGlyph {
 impl ShapedGlyph {
     /// Whether the glyph is a space.
     pub fn is_space(&self) -> bool {
-        matches!(self.c, ' ' | '\u{00A0}' | '　')
+        matches!(self.c, ' ' | '\u{00A0}' | '\u{3000}')
     }
 
     /// Whether the glyph is justifiable.
@@ -99,6 +100,7 @@ impl<'a> ShapedText<'a> {
         let lang = TextElem::lang_in(self.styles);
         let decos = TextElem::deco_in(self.styles);
         let fill = TextElem::fill_in(self.styles);
+        let visible = TextElem::visible_whitespace_in(self.styles);
 
         for ((font, y_offset), group) in
             self.glyphs.as_ref().group_by_key(|g| (g.font.clone(), g.y_offset))
@@ -107,7 +109,12 @@ impl<'a> ShapedText<'a> {
             let glyphs = group
                 .iter()
                 .map(|glyph| Glyph {
-                    id: glyph.glyph_id,
+                    // A visible whitespace marker keeps the original
+                    // character so that text offsets stay intact.
+                    id: visible
+                        .then(|| visible_glyph(&glyph.font, glyph.c))
+                        .flatten()
+                        .unwrap_or(glyph.glyph_id),
                     x_advance: glyph.x_advance
                         + if glyph.is_justifiable() {
                             frame.size_mut().x += justification;
@@ -122,7 +129,14 @@ impl<'a> ShapedText<'a> {
                 })
                 .collect();
 
-            let item = TextItem { font, size: self.size, lang, fill, glyphs };
+            let item = TextItem {
+                font,
+                size: self.size,
+                lang,
+                dir: self.dir,
+                fill,
+                glyphs,
+            };
             let layer = frame.layer();
             let width = item.width();
 
@@ -359,7 +373,8 @@ pub fn shape<'a>(
     };
 
     if !text.is_empty() {
-        shape_segment(&mut ctx, 0, text, families(styles));
+        let preferred = script_families(styles, text);
+        shape_segment(&mut ctx, 0, text, preferred.into_iter().chain(families(styles)));
     }
 
     track_and_space(&mut ctx);
@@ -534,6 +549,8 @@ fn track_and_space(ctx: &mut ShapingContext) {
     let tracking = Em::from_length(TextElem::tracking_in(ctx.styles), ctx.size);
     let spacing =
         TextElem::spacing_in(ctx.styles).map(|abs| Em::from_length(abs, ctx.size));
+    let tabular =
+        TextElem::number_width_in(ctx.styles) == Smart::Custom(NumberWidth::Tabular);
 
     let mut glyphs = ctx.glyphs.iter_mut().peekable();
     while let Some(glyph) = glyphs.next() {
@@ -542,6 +559,18 @@ fn track_and_space(ctx: &mut ShapingContext) {
             glyph.x_advance -= nbsp_delta(&glyph.font).unwrap_or_default();
         }
 
+        // Center digits in the widest digit's advance in case the font has no
+        // tabular figures.
+        if tabular
+            && glyph.c.is_ascii_digit()
+            && !has_feature(&glyph.font, Tag::from_bytes(b"tnum"))
+        {
+            if let Some(width) = digit_width(&glyph.font) {
+                glyph.x_offset += (width - glyph.x_advance) / 2.0;
+                glyph.x_advance = width;
+            }
+        }
+
         if glyph.is_space() {
             glyph.x_advance = spacing.relative_to(glyph.x_advance);
         }
@@ -552,6 +581,16 @@ fn track_and_space(ctx: &mut ShapingContext) {
     }
 }
 
+/// The glyph that makes an invisible character visible.
+fn visible_glyph(font: &Font, c: char) -> Option<u16> {
+    let substitute = match c {
+        ' ' | '\u{00A0}' | '\u{3000}' => '·',
+        '\t' => '→',
+        _ => return None,
+    };
+    Some(font.ttf().glyph_index(substitute)?.0)
+}
+
 /// Difference between non-breaking and normal space.
 fn nbsp_delta(font: &Font) -> Option<Em> {
     let space = font.ttf().glyph_index(' ')?.0;
@@ -559,6 +598,21 @@ fn nbsp_delta(font: &Font) -> Option<Em> {
     Some(font.advance(nbsp)? - font.advance(space)?)
 }
 
+/// Whether the font's substitution table defines the given feature.
+pub(crate) fn has_feature(font: &Font, tag: Tag) -> bool {
+    font.ttf()
+        .tables()
+        .gsub
+        .map_or(false, |gsub| gsub.features.into_iter().any(|feature| feature.tag == tag))
+}
+
+/// The advance of the widest digit in the font.
+fn digit_width(font: &Font) -> Option<Em> {
+    ('0'..='9')
+        .filter_map(|c| font.advance(font.ttf().glyph_index(c)?.0))
+        .max()
+}
+
 /// Resolve the font variant.
 pub fn variant(styles: StyleChain) -> FontVariant {
     let mut variant = FontVariant::new(
@@ -599,6 +653,15 @@ pub fn families(styles: StyleChain) -> impl Iterator<Item = FontFamily> + Clone
         .chain(tail.iter().copied().map(FontFamily::new))
 }
 
+/// The font families designated for the script of the given text.
+fn script_families(styles: StyleChain, text: &str) -> Vec<FontFamily> {
+    text.chars()
+        .map(|c| c.script())
+        .find(|s| !matches!(s, Script::Unknown | Script::Common | Script::Inherited))
+        .and_then(|script| TextElem::script_fonts_in(styles).get(script).cloned())
+        .map_or_else(Vec::new, |list| list.0)
+}
+
 /// Collect the tags of the OpenType features to apply.
 fn tags(styles: StyleChain) -> Vec<Feature> {
     let mut tags = vec![];
@@ -40,9 +40,14 @@ pub struct LineElem {
     ///   to `{1pt}`.
     /// - A stroke combined from color and thickness using the `+` operator as
     ///   in `{2pt + red}`.
+    /// - A dictionary with any of the keys `paint`, `thickness` and `dash`.
+    ///   The dash pattern can be `{"dotted"}`, `{"dashed"}`, `{"dash-dotted"}`
+    ///   or `{none}` for a solid line. Its dashes and gaps scale with the
+    ///   thickness.
     ///
     /// ```example
     /// #line(length: 100%, stroke: 2pt + red)
+    /// #line(length: 100%, stroke: (thickness: 2pt, dash: "dashed"))
     /// ```
     #[resolve]
     #[fold]
@@ -2,8 +2,10 @@
 
 mod image;
 mod line;
+mod polygon;
 mod shape;
 
 pub use self::image::*;
 pub use self::line::*;
+pub use self::polygon::*;
 pub use self::shape::*;
@@ -59,6 +59,10 @@ pub struct RectElem {
     ///   - `rest`: The stroke on all sides except those for which the
     ///     dictionary explicitly sets a size.
     ///
+    ///   Each side's stroke can itself be a dictionary with a dash pattern as
+    ///   described for [lines]($func/line.stroke), e.g.
+    ///   `{(rest: (dash: "dashed"))}`.
+    ///
     /// ```example
     /// #stack(
     ///   dir: ltr,
@@ -115,6 +115,16 @@ impl Frame {
         self.items.iter()
     }
 
+    /// An iterator over the items inside this frame and all nested groups.
+    ///
+    /// Each item comes with the transform from its own coordinates, with the
+    /// origin at the item's position, to the coordinates of this frame. This
+    /// includes the positions and transformations of all enclosing groups.
+    /// The groups themselves are not yielded.
+    pub fn flat_items(&self) -> FlatItems<'_> {
+        FlatItems { stack: vec![(Transform::identity(), self.items())] }
+    }
+
     /// Approximately recover the text inside of the frame and its children.
     pub fn text(&self) -> EcoString {
         let mut text = EcoString::new();
@@ -359,6 +369,7 @@ impl Frame {
                 Geometry::Line(Point::with_x(self.size.x)).stroked(Stroke {
                     paint: Color::RED.into(),
                     thickness: Abs::pt(1.0),
+                    dash: None,
                 }),
                 Span::detached(),
             ),
@@ -386,6 +397,7 @@ impl Frame {
                 Geometry::Line(Point::with_x(self.size.x)).stroked(Stroke {
                     paint: Color::GREEN.into(),
                     thickness: Abs::pt(1.0),
+                    dash: None,
                 }),
                 Span::detached(),
             ),
@@ -402,6 +414,36 @@ impl Debug for Frame {
     }
 }
 
+/// An iterator over the items of a frame and its nested groups.
+///
+/// Created by [`Frame::flat_items`].
+pub struct FlatItems<'a> {
+    stack: Vec<(Transform, std::slice::Iter<'a, (Point, FrameItem)>)>,
+}
+
+impl<'a> Iterator for FlatItems<'a> {
+    type Item = (Transform, &'a FrameItem);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (ts, items) = self.stack.last_mut()?;
+            let Some((pos, item)) = items.next() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts.pre_concat(group.transform);
+                    self.stack.push((ts, group.frame.items()));
+                }
+                _ => return Some((ts, item)),
+            }
+        }
+    }
+}
+
 /// The building block frames are composed of.
 #[derive(Clone, Hash)]
 pub enum FrameItem {
@@ -469,6 +511,9 @@ pub struct TextItem {
     pub fill: Paint,
     /// The natural language of the text.
     pub lang: Lang,
+    /// The direction of the text. The glyphs are in visual order, so their
+    /// offsets decrease from glyph to glyph in right-to-left text.
+    pub dir: Dir,
     /// The glyphs.
     pub glyphs: Vec<Glyph>,
 }
@@ -665,3 +710,72 @@ cast_to_value! {
         "y" => Value::Length(v.point.y.into()),
     })
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::geom::Ratio;
+
+    /// Load a bundled font.
+    pub fn font() -> Font {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/fonts/PTSans-Regular.ttf");
+        Font::new(std::fs::read(path).unwrap().into(), 0).unwrap()
+    }
+
+    /// A 10pt text run whose glyphs are 5pt wide each. The glyphs are given
+    /// as their characters, spans and offsets.
+    pub fn text(font: &Font, dir: Dir, glyphs: &[(char, Span, u16)]) -> FrameItem {
+        FrameItem::Text(TextItem {
+            font: font.clone(),
+            size: Abs::pt(10.0),
+            fill: Color::BLACK.into(),
+            lang: Lang::ENGLISH,
+            dir,
+            glyphs: glyphs
+                .iter()
+                .map(|&(c, span, offset)| Glyph {
+                    id: 1,
+                    x_advance: Em::new(0.5),
+                    x_offset: Em::zero(),
+                    c,
+                    span,
+                    offset,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_flat_items() {
+        let shape = || {
+            FrameItem::Shape(
+                Geometry::Line(Point::zero()).stroked(Stroke::default()),
+                Span::detached(),
+            )
+        };
+
+        let mut inner = Frame::new(Size::splat(Abs::pt(20.0)));
+        inner.push(Point::new(Abs::pt(1.0), Abs::pt(2.0)), shape());
+        inner.transform(Transform::scale(Ratio::new(2.0), Ratio::new(2.0)));
+        let mut middle = Frame::new(Size::splat(Abs::pt(40.0)));
+        middle.push_frame(Point::new(Abs::pt(3.0), Abs::pt(4.0)), inner);
+        let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+        page.push(Point::splat(Abs::pt(5.0)), shape());
+        page.push_frame(Point::new(Abs::pt(10.0), Abs::pt(20.0)), middle);
+        page.push(Point::splat(Abs::pt(50.0)), shape());
+
+        // Groups are skipped and the origins of the other items are mapped
+        // to the page.
+        let origins: Vec<_> = page
+            .flat_items()
+            .map(|(ts, _)| {
+                let origin = Point::zero().transform(ts);
+                (origin.x.to_pt(), origin.y.to_pt())
+            })
+            .collect();
+        assert_eq!(origins, [(5.0, 5.0), (15.0, 28.0), (50.0, 50.0)]);
+    }
+}
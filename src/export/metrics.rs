@@ -0,0 +1,245 @@
+use serde::{Deserialize, Serialize};
+
+use crate::doc::{Document, Frame, FrameItem, TextItem};
+use crate::geom::{Abs, Point, Transform};
+
+/// The version of the metrics schema.
+///
+/// This is increased whenever a field of the metrics types is added, removed
+/// or renamed and whenever the meaning of a value changes, for instance its
+/// unit or how lines are grouped. Metrics recorded with a different version
+/// cannot be compared with fresh ones, so tests that store metrics as
+/// fixtures should check the stored `version` against this constant and
+/// re-record the fixture on a mismatch instead of failing on the difference.
+pub const METRICS_VERSION: u32 = 1;
+
+/// Extract the layout metrics of a document.
+///
+/// The result contains the dimensions of each page, the positions of all
+/// glyphs and the metrics of each line in a stable, serializable form. It is
+/// meant for making assertions about layouts in automated tests. All lengths
+/// are in points and all positions are relative to the top-left corner of the
+/// page, with the transformations of enclosing groups applied.
+pub fn metrics(document: &Document) -> DocumentMetrics {
+    DocumentMetrics {
+        version: METRICS_VERSION,
+        pages: document.pages.iter().map(page_metrics).collect(),
+    }
+}
+
+/// The layout metrics of a whole document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentMetrics {
+    /// The version of the schema, see [`METRICS_VERSION`].
+    pub version: u32,
+    /// The metrics of each page.
+    pub pages: Vec<PageMetrics>,
+}
+
+/// The layout metrics of a single page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageMetrics {
+    /// The width of the page.
+    pub width: f64,
+    /// The height of the page.
+    pub height: f64,
+    /// The lines on the page, from top to bottom.
+    pub lines: Vec<LineMetrics>,
+}
+
+/// The metrics of a line, that is, of all text runs sharing a baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineMetrics {
+    /// The vertical position of the baseline.
+    pub baseline: f64,
+    /// The horizontal position of the line's left edge.
+    pub left: f64,
+    /// The horizontal position of the line's right edge.
+    pub right: f64,
+    /// The largest ascender of the fonts in the line.
+    pub ascent: f64,
+    /// The largest descender of the fonts in the line.
+    pub descent: f64,
+    /// The glyphs in the line, in the order in which they were laid out.
+    pub glyphs: Vec<GlyphMetrics>,
+}
+
+/// The metrics of a single glyph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlyphMetrics {
+    /// The first character of the glyph's cluster.
+    pub c: char,
+    /// The glyph's index in its font.
+    pub id: u16,
+    /// The family of the glyph's font.
+    pub font: String,
+    /// The font size.
+    pub size: f64,
+    /// The horizontal position of the glyph's origin.
+    pub x: f64,
+    /// The vertical position of the glyph's origin.
+    pub y: f64,
+    /// The advance width of the glyph.
+    pub advance: f64,
+}
+
+/// Extract the metrics of a page.
+fn page_metrics(frame: &Frame) -> PageMetrics {
+    let mut lines = vec![];
+    for (ts, item) in frame.flat_items() {
+        if let FrameItem::Text(text) = item {
+            add_text(text, ts, &mut lines);
+        }
+    }
+
+    lines.sort_by(|a: &LineMetrics, b| a.baseline.total_cmp(&b.baseline));
+    PageMetrics {
+        width: frame.width().to_pt(),
+        height: frame.height().to_pt(),
+        lines,
+    }
+}
+
+/// Add a text run to the line with the same baseline. The transform maps from
+/// the run's coordinates to the page.
+fn add_text(text: &TextItem, ts: Transform, lines: &mut Vec<LineMetrics>) {
+    let start = Point::zero().transform(ts);
+    let end = Point::with_x(text.width()).transform(ts);
+    let baseline = start.y.to_pt();
+
+    let index = match lines.iter().position(|line| line.baseline == baseline) {
+        Some(index) => index,
+        None => {
+            lines.push(LineMetrics {
+                baseline,
+                left: f64::INFINITY,
+                right: f64::NEG_INFINITY,
+                ascent: 0.0,
+                descent: 0.0,
+                glyphs: vec![],
+            });
+            lines.len() - 1
+        }
+    };
+
+    let line = &mut lines[index];
+    let metrics = text.font.metrics();
+    line.left = line.left.min(start.x.to_pt());
+    line.right = line.right.max(end.x.to_pt());
+    line.ascent = line.ascent.max(metrics.ascender.at(text.size).to_pt());
+    line.descent = line.descent.max(-metrics.descender.at(text.size).to_pt());
+
+    let family = &text.font.info().family;
+    let mut x = Abs::zero();
+    for glyph in &text.glyphs {
+        let offset = x + glyph.x_offset.at(text.size);
+        let origin = Point::with_x(offset).transform(ts);
+        line.glyphs.push(GlyphMetrics {
+            c: glyph.c,
+            id: glyph.id,
+            font: family.clone(),
+            size: text.size.to_pt(),
+            x: origin.x.to_pt(),
+            y: origin.y.to_pt(),
+            advance: glyph.x_advance.at(text.size).to_pt(),
+        });
+        x += glyph.x_advance.at(text.size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::tests::font;
+    use crate::doc::GroupItem;
+    use crate::font::Font;
+    use crate::geom::{Dir, Ratio, Size};
+    use crate::syntax::Span;
+
+    /// A left-to-right run of glyphs for the given characters.
+    fn text(font: &Font, text: &str) -> FrameItem {
+        let glyphs: Vec<_> = text.chars().map(|c| (c, Span::detached(), 0)).collect();
+        crate::doc::tests::text(font, Dir::LTR, &glyphs)
+    }
+
+    fn document(pages: Vec<Frame>) -> Document {
+        Document { pages, title: None, author: vec![] }
+    }
+
+    #[test]
+    fn test_metrics_pages() {
+        let pages = vec![
+            Frame::new(Size::new(Abs::pt(100.0), Abs::pt(200.0))),
+            Frame::new(Size::new(Abs::pt(300.0), Abs::pt(50.0))),
+        ];
+
+        let metrics = metrics(&document(pages));
+        assert_eq!(metrics.version, METRICS_VERSION);
+        assert_eq!(metrics.pages.len(), 2);
+        assert_eq!((metrics.pages[0].width, metrics.pages[0].height), (100.0, 200.0));
+        assert_eq!((metrics.pages[1].width, metrics.pages[1].height), (300.0, 50.0));
+        assert!(metrics.pages.iter().all(|page| page.lines.is_empty()));
+    }
+
+    #[test]
+    fn test_metrics_lines() {
+        let font = font();
+        let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+        page.push(Point::new(Abs::pt(10.0), Abs::pt(40.0)), text(&font, "ab"));
+        page.push(Point::new(Abs::pt(30.0), Abs::pt(20.0)), text(&font, "cd"));
+        page.push(Point::new(Abs::pt(10.0), Abs::pt(20.0)), text(&font, "e"));
+
+        let metrics = metrics(&document(vec![page]));
+        let lines = &metrics.pages[0].lines;
+        assert_eq!(lines.len(), 2);
+
+        // Lines are sorted from top to bottom and runs with the same baseline
+        // are merged in layout order.
+        assert_eq!(lines[0].baseline, 20.0);
+        assert_eq!((lines[0].left, lines[0].right), (10.0, 40.0));
+        let chars: String = lines[0].glyphs.iter().map(|glyph| glyph.c).collect();
+        assert_eq!(chars, "cde");
+        assert_eq!(lines[1].baseline, 40.0);
+        assert_eq!((lines[1].left, lines[1].right), (10.0, 20.0));
+
+        let ascent = font.metrics().ascender.at(Abs::pt(10.0)).to_pt();
+        assert_eq!(lines[0].ascent, ascent);
+
+        let glyph = &lines[1].glyphs[1];
+        assert_eq!((glyph.c, glyph.id), ('b', 1));
+        assert_eq!(glyph.font, "PT Sans");
+        assert_eq!((glyph.size, glyph.advance), (10.0, 5.0));
+        assert_eq!((glyph.x, glyph.y), (15.0, 40.0));
+    }
+
+    #[test]
+    fn test_metrics_transformed_groups() {
+        let font = font();
+        let mut inner = Frame::new(Size::splat(Abs::pt(20.0)));
+        inner.push(Point::new(Abs::pt(1.0), Abs::pt(2.0)), text(&font, "ab"));
+        let mut middle = Frame::new(Size::splat(Abs::pt(40.0)));
+        middle.push(
+            Point::new(Abs::pt(3.0), Abs::pt(4.0)),
+            FrameItem::Group(GroupItem {
+                frame: inner,
+                transform: Transform::scale(Ratio::new(2.0), Ratio::new(2.0)),
+                clips: false,
+            }),
+        );
+        let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+        page.push(
+            Point::new(Abs::pt(10.0), Abs::pt(20.0)),
+            FrameItem::Group(GroupItem::new(middle)),
+        );
+
+        let metrics = metrics(&document(vec![page]));
+        let line = &metrics.pages[0].lines[0];
+
+        // The inner origin is at (13, 24) on the page and scaled by two.
+        assert_eq!(line.baseline, 28.0);
+        assert_eq!((line.left, line.right), (15.0, 35.0));
+        let xs: Vec<_> = line.glyphs.iter().map(|glyph| glyph.x).collect();
+        assert_eq!(xs, [15.0, 25.0]);
+        assert!(line.glyphs.iter().all(|glyph| glyph.y == 28.0));
+    }
+}
@@ -1,7 +1,11 @@
 //! Exporting into external formats.
 
+mod metrics;
 mod pdf;
 mod render;
+mod spreads;
 
+pub use self::metrics::*;
 pub use self::pdf::pdf;
 pub use self::render::render;
+pub use self::spreads::spreads;
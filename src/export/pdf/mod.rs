@@ -224,6 +224,9 @@ impl AbsExt for Abs {
 /// Additional methods for [`Em`].
 trait EmExt {
     /// Convert an em length to a number of PDF font units.
+    ///
+    /// PDF glyph space always has 1000 units per em, independently of the
+    /// units per em of the font itself.
     fn to_font_units(self) -> f32;
 }
 
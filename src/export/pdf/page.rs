@@ -268,6 +268,15 @@ impl PageContext<'_, '_> {
             }
 
             self.content.set_line_width(stroke.thickness.to_f32());
+
+            // The dash array scales with the thickness, so compare the
+            // resulting arrays rather than just the patterns.
+            let array = dash_array(&stroke);
+            let previous = self.state.stroke.as_ref().map(dash_array).unwrap_or_default();
+            if previous != array {
+                self.content.set_dash_pattern(array.iter().map(|v| v.to_f32()), 0.0);
+            }
+
             self.state.stroke = Some(stroke);
         }
     }
@@ -284,6 +293,14 @@ impl PageContext<'_, '_> {
     }
 }
 
+/// The PDF dash array for a stroke. Empty for solid strokes.
+fn dash_array(stroke: &Stroke) -> Vec<Abs> {
+    match stroke.dash {
+        Some(dash) if !stroke.thickness.is_zero() => dash.array(stroke.thickness),
+        _ => vec![],
+    }
+}
+
 /// Encode a frame into the content stream.
 fn write_frame(ctx: &mut PageContext, frame: &Frame) {
     for &(pos, ref item) in frame.items() {
@@ -485,3 +502,40 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
 
     ctx.links.push((dest.clone(), rect));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::Document;
+    use crate::geom::DashPattern;
+    use crate::syntax::Span;
+
+    #[test]
+    fn test_dash_pattern_follows_thickness() {
+        let line = |thickness| {
+            let stroke = Stroke {
+                thickness: Abs::pt(thickness),
+                dash: Some(DashPattern::Dashed),
+                ..Stroke::default()
+            };
+            let geometry = Geometry::Line(Point::with_x(Abs::pt(10.0)));
+            FrameItem::Shape(
+                Shape { geometry, fill: None, stroke: Some(stroke) },
+                Span::detached(),
+            )
+        };
+
+        let mut frame = Frame::new(Size::new(Abs::pt(10.0), Abs::pt(10.0)));
+        frame.push(Point::zero(), line(1.0));
+        frame.push(Point::with_y(Abs::pt(5.0)), line(2.0));
+
+        let document = Document::default();
+        let mut ctx = PdfContext::new(&document);
+        construct_page(&mut ctx, &frame);
+
+        let content = ctx.pages.pop().unwrap().content.finish();
+        let content = String::from_utf8_lossy(&content);
+        assert!(content.contains("[3 3] 0 d"));
+        assert!(content.contains("[6 6] 0 d"));
+    }
+}
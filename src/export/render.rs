@@ -312,9 +312,17 @@ fn render_shape(
         canvas.fill_path(&path, &paint, rule, ts, mask);
     }
 
-    if let Some(Stroke { paint, thickness }) = shape.stroke {
+    if let Some(Stroke { paint, thickness, dash }) = shape.stroke {
         let paint = paint.into();
-        let stroke = sk::Stroke { width: thickness.to_f32(), ..Default::default() };
+        let dash = dash.and_then(|dash| {
+            let array = dash.array(thickness).iter().map(|v| v.to_f32()).collect();
+            sk::StrokeDash::new(array, 0.0)
+        });
+        let stroke = sk::Stroke {
+            width: thickness.to_f32(),
+            dash,
+            ..Default::default()
+        };
         canvas.stroke_path(&path, &paint, &stroke, ts, mask);
     }
 
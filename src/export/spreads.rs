@@ -0,0 +1,171 @@
+use std::num::NonZeroUsize;
+
+use crate::doc::{Destination, Document, Frame, FrameItem, GroupItem, Meta, Position};
+use crate::geom::{Abs, Point, Size};
+
+/// Compose the pages of a document into facing-page spreads.
+///
+/// The first page stands alone as a right-hand page. All following pages are
+/// paired up such that each even page is placed to the left of the next odd
+/// page, separated by the `gutter`. A trailing even page also stands alone.
+/// Links to positions in the document are moved along with their target
+/// pages. The resulting document can be passed to any exporter for proofing.
+///
+/// The pages are composed exactly as they were laid out. In particular, page
+/// margins are not mirrored: A left-hand page keeps the margins it was laid
+/// out with.
+pub fn spreads(document: &Document, gutter: Abs) -> Document {
+    // Determine the spread number and the horizontal offset within the spread
+    // for each page.
+    let mut placements = vec![];
+    let mut count = 0;
+    for i in 0..document.pages.len() {
+        if i == 0 || i % 2 == 1 {
+            count += 1;
+            placements.push((count, Abs::zero()));
+        } else {
+            placements.push((count, document.pages[i - 1].width() + gutter));
+        }
+    }
+
+    let mut pages = vec![];
+    let mut iter = document.pages.iter().map(|frame| relink(frame, &placements));
+
+    if let Some(first) = iter.next() {
+        pages.push(first);
+    }
+
+    while let Some(left) = iter.next() {
+        match iter.next() {
+            Some(right) => pages.push(spread(left, right, gutter)),
+            None => pages.push(left),
+        }
+    }
+
+    Document {
+        pages,
+        title: document.title.clone(),
+        author: document.author.clone(),
+    }
+}
+
+/// Place two pages side by side.
+fn spread(left: Frame, right: Frame, gutter: Abs) -> Frame {
+    let size = Size::new(
+        left.width() + gutter + right.width(),
+        left.height().max(right.height()),
+    );
+
+    let mut frame = Frame::new(size);
+    let offset = Point::with_x(left.width() + gutter);
+    frame.push_frame(Point::zero(), left);
+    frame.push_frame(offset, right);
+    frame
+}
+
+/// Point the position links in a frame to where their targets end up.
+fn relink(frame: &Frame, placements: &[(usize, Abs)]) -> Frame {
+    let mut relinked = Frame::new(frame.size());
+    if frame.has_baseline() {
+        relinked.set_baseline(frame.baseline());
+    }
+
+    for (pos, item) in frame.items() {
+        let item = match item {
+            FrameItem::Group(group) => FrameItem::Group(GroupItem {
+                frame: relink(&group.frame, placements),
+                transform: group.transform,
+                clips: group.clips,
+            }),
+            FrameItem::Meta(Meta::Link(Destination::Position(position)), size) => {
+                let position = move_position(*position, placements);
+                FrameItem::Meta(Meta::Link(Destination::Position(position)), *size)
+            }
+            item => item.clone(),
+        };
+        relinked.push(*pos, item);
+    }
+
+    relinked
+}
+
+/// Move a position on a page to the same spot on the page's spread.
+fn move_position(position: Position, placements: &[(usize, Abs)]) -> Position {
+    let Some(&(spread, offset)) = placements.get(position.page.get() - 1) else {
+        return position;
+    };
+
+    Position {
+        page: NonZeroUsize::new(spread).unwrap(),
+        point: position.point + Point::with_x(offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spreads() {
+        let page = |width| Frame::new(Size::new(Abs::pt(width), Abs::pt(20.0)));
+        let document = |pages| Document { pages, title: None, author: vec![] };
+        let widths = |count| {
+            let pages = (0..count).map(|i| page(10.0 + i as f64)).collect();
+            spreads(&document(pages), Abs::pt(1.0))
+                .pages
+                .iter()
+                .map(|frame| frame.width().to_pt())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(widths(0), Vec::<f64>::new());
+        assert_eq!(widths(1), [10.0]);
+        assert_eq!(widths(2), [10.0, 11.0]);
+        assert_eq!(widths(3), [10.0, 24.0]);
+        assert_eq!(widths(4), [10.0, 24.0, 13.0]);
+        assert_eq!(widths(5), [10.0, 24.0, 28.0]);
+    }
+
+    #[test]
+    fn test_spreads_relink() {
+        let size = Size::new(Abs::pt(10.0), Abs::pt(20.0));
+        let link = |page, x| {
+            let position = Position {
+                page: NonZeroUsize::new(page).unwrap(),
+                point: Point::new(Abs::pt(x), Abs::pt(5.0)),
+            };
+            FrameItem::Meta(Meta::Link(Destination::Position(position)), Size::zero())
+        };
+
+        // Page 1 links to the third page and the second page links to the
+        // first one from within a group.
+        let mut first = Frame::new(size);
+        first.push(Point::zero(), link(3, 2.0));
+        let mut inner = Frame::new(size);
+        inner.push(Point::zero(), link(1, 4.0));
+        let mut second = Frame::new(size);
+        second.push(Point::zero(), FrameItem::Group(GroupItem::new(inner)));
+        let third = Frame::new(size);
+
+        let document = Document {
+            pages: vec![first, second, third],
+            title: None,
+            author: vec![],
+        };
+
+        let spread = spreads(&document, Abs::pt(1.0));
+        let targets: Vec<_> = spread
+            .pages
+            .iter()
+            .flat_map(Frame::flat_items)
+            .filter_map(|(_, item)| match item {
+                FrameItem::Meta(Meta::Link(Destination::Position(position)), _) => {
+                    Some((position.page.get(), position.point.x.to_pt()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(targets, [(2, 13.0), (1, 4.0)]);
+    }
+}
@@ -527,4 +527,40 @@ mod tests {
         let coverage = Coverage::from_vec(codepoints.clone());
         assert_eq!(coverage.iter().collect::<Vec<_>>(), codepoints);
     }
+
+    #[test]
+    fn test_select_closest_variant() {
+        let mut book = FontBook::new();
+        let variants = [(300, 1000), (400, 1000), (700, 1000), (400, 750)];
+        for (weight, stretch) in variants {
+            book.push(FontInfo {
+                family: "Test".into(),
+                variant: FontVariant::new(
+                    FontStyle::Normal,
+                    FontWeight::from_number(weight),
+                    FontStretch::from_number(stretch),
+                ),
+                flags: FontFlags::empty(),
+                coverage: Coverage::from_vec(vec![]),
+            });
+        }
+
+        let select = |weight, stretch| {
+            book.select(
+                "test",
+                FontVariant::new(
+                    FontStyle::Normal,
+                    FontWeight::from_number(weight),
+                    FontStretch::from_number(stretch),
+                ),
+            )
+        };
+
+        assert_eq!(select(400, 1000), Some(1));
+        assert_eq!(select(500, 1000), Some(1));
+        assert_eq!(select(600, 1000), Some(2));
+        assert_eq!(select(100, 1000), Some(0));
+        assert_eq!(select(900, 750), Some(3));
+        assert_eq!(select(700, 800), Some(3));
+    }
 }
@@ -23,6 +23,13 @@ impl Em {
     }
 
     /// Create an em length from font units at the given units per em.
+    ///
+    /// Fonts express their metrics in units of their own em square, which
+    /// commonly is 1000 units for CFF and 2048 units for TrueType fonts. All
+    /// font units should pass through here (or [`Font::to_em`]) so that no
+    /// particular number of units per em is assumed.
+    ///
+    /// [`Font::to_em`]: crate::font::Font::to_em
     pub fn from_units(units: impl Into<f64>, units_per_em: f64) -> Self {
         Self(Scalar(units.into() / units_per_em))
     }
@@ -150,3 +157,17 @@ impl Resolve for Em {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_em_from_units() {
+        let size = Abs::pt(10.0);
+        assert_eq!(Em::from_units(500, 1000.0).at(size), Abs::pt(5.0));
+        assert_eq!(Em::from_units(1024, 2048.0).at(size), Abs::pt(5.0));
+        assert_eq!(Em::from_units(2048, 2048.0), Em::one());
+        assert_eq!(Em::from_units(-512, 2048.0), Em::new(-0.25));
+    }
+}
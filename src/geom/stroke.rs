@@ -7,6 +7,8 @@ pub struct Stroke {
     pub paint: Paint,
     /// The stroke's thickness.
     pub thickness: Abs,
+    /// The pattern of dashes and gaps, if the stroke isn't solid.
+    pub dash: Option<DashPattern>,
 }
 
 impl Default for Stroke {
@@ -14,21 +16,25 @@ impl Default for Stroke {
         Self {
             paint: Paint::Solid(Color::BLACK),
             thickness: Abs::pt(1.0),
+            dash: None,
         }
     }
 }
 
 /// A partial stroke representation.
 ///
-/// In this representation, both fields are optional so that you can pass either
+/// In this representation, all fields are optional so that you can pass either
 /// just a paint (`red`), just a thickness (`0.1em`) or both (`2pt + red`) where
-/// this is expected.
+/// this is expected. A dictionary with the keys `paint`, `thickness` and `dash`
+/// can be used to additionally configure a dash pattern.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PartialStroke<T = Length> {
     /// The stroke's paint.
     pub paint: Smart<Paint>,
     /// The stroke's thickness.
     pub thickness: Smart<T>,
+    /// The stroke's dash pattern.
+    pub dash: Smart<Option<DashPattern>>,
 }
 
 impl PartialStroke<Abs> {
@@ -37,6 +43,7 @@ impl PartialStroke<Abs> {
         Stroke {
             paint: self.paint.unwrap_or(default.paint),
             thickness: self.thickness.unwrap_or(default.thickness),
+            dash: self.dash.unwrap_or(default.dash),
         }
     }
 
@@ -48,6 +55,20 @@ impl PartialStroke<Abs> {
 
 impl<T: Debug> Debug for PartialStroke<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Smart::Custom(dash) = self.dash {
+            f.write_str("(")?;
+            if let Smart::Custom(paint) = self.paint {
+                write!(f, "paint: {paint:?}, ")?;
+            }
+            if let Smart::Custom(thickness) = &self.thickness {
+                write!(f, "thickness: {thickness:?}, ")?;
+            }
+            return match dash {
+                Some(dash) => write!(f, "dash: \"{}\")", dash.as_str()),
+                None => f.write_str("dash: none)"),
+            };
+        }
+
         match (self.paint, &self.thickness) {
             (Smart::Custom(paint), Smart::Custom(thickness)) => {
                 write!(f, "{thickness:?} + {paint:?}")
@@ -62,12 +83,23 @@ impl<T: Debug> Debug for PartialStroke<T> {
 cast_from_value! {
     PartialStroke: "stroke",
     thickness: Length => Self {
-        paint: Smart::Auto,
         thickness: Smart::Custom(thickness),
+        ..Default::default()
     },
     color: Color => Self {
         paint: Smart::Custom(color.into()),
-        thickness: Smart::Auto,
+        ..Default::default()
+    },
+    mut dict: Dict => {
+        let paint = dict.take("paint").ok().map(Value::cast).transpose()?;
+        let thickness = dict.take("thickness").ok().map(Value::cast).transpose()?;
+        let dash = dict.take("dash").ok().map(Value::cast).transpose()?;
+        dict.finish(&["paint", "thickness", "dash"])?;
+        Self {
+            paint: paint.map_or(Smart::Auto, Smart::Custom),
+            thickness: thickness.map_or(Smart::Auto, Smart::Custom),
+            dash: dash.map_or(Smart::Auto, Smart::Custom),
+        }
     },
 }
 
@@ -78,6 +110,7 @@ impl Resolve for PartialStroke {
         PartialStroke {
             paint: self.paint,
             thickness: self.thickness.resolve(styles),
+            dash: self.dash,
         }
     }
 }
@@ -89,6 +122,54 @@ impl Fold for PartialStroke<Abs> {
         Self {
             paint: self.paint.or(outer.paint),
             thickness: self.thickness.or(outer.thickness),
+            dash: self.dash.or(outer.dash),
         }
     }
 }
+
+/// A pattern of dashes and gaps along a stroke.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DashPattern {
+    /// Short dashes that are as long as the stroke is thick.
+    Dotted,
+    /// Longer dashes.
+    Dashed,
+    /// Alternating long and short dashes.
+    DashDotted,
+}
+
+impl DashPattern {
+    /// The name of the pattern as it is written in Typst.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dotted => "dotted",
+            Self::Dashed => "dashed",
+            Self::DashDotted => "dash-dotted",
+        }
+    }
+
+    /// The lengths of the alternating dashes and gaps for a stroke with the
+    /// given thickness, starting with a dash.
+    pub fn array(self, thickness: Abs) -> Vec<Abs> {
+        let factors: &[f64] = match self {
+            Self::Dotted => &[1.0, 2.0],
+            Self::Dashed => &[3.0, 3.0],
+            Self::DashDotted => &[3.0, 2.0, 1.0, 2.0],
+        };
+        factors.iter().map(|&factor| factor * thickness).collect()
+    }
+}
+
+cast_from_value! {
+    DashPattern,
+    /// Short dashes that are as long as the stroke is thick.
+    "dotted" => Self::Dotted,
+    /// Longer dashes.
+    "dashed" => Self::Dashed,
+    /// Alternating long and short dashes.
+    "dash-dotted" => Self::DashDotted,
+}
+
+cast_to_value! {
+    v: DashPattern => Value::from(v.as_str())
+}
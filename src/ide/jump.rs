@@ -2,8 +2,8 @@ use std::num::NonZeroUsize;
 
 use ecow::EcoString;
 
-use crate::doc::{Destination, Frame, FrameItem, Meta, Position};
-use crate::geom::{Geometry, Point, Size};
+use crate::doc::{Destination, Frame, FrameItem, Meta, Position, TextItem};
+use crate::geom::{Abs, Dir, Geometry, Point, Size};
 use crate::model::Introspector;
 use crate::syntax::{LinkedNode, Source, SourceId, Span, SyntaxKind};
 use crate::World;
@@ -138,6 +138,138 @@ pub fn jump_from_cursor(
     None
 }
 
+/// The visual position of a cursor on a page.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Caret {
+    /// The page the caret is on.
+    pub page: NonZeroUsize,
+    /// The top end of the caret.
+    pub point: Point,
+    /// The height of the caret.
+    pub height: Abs,
+}
+
+/// Find the caret in the document for a cursor position.
+///
+/// The caret is placed at the edge of the glyph at the cursor at which the
+/// glyph's text starts, which is its right edge in right-to-left text. It is
+/// as high as the font's ascender and descender. The caret's position and
+/// height are in page coordinates, with the transformations of enclosing
+/// groups applied.
+pub fn caret_from_cursor(
+    frames: &[Frame],
+    source: &Source,
+    cursor: usize,
+) -> Option<Caret> {
+    let node = LinkedNode::new(source.root()).leaf_at(cursor)?;
+    if node.kind() != SyntaxKind::Text {
+        return None;
+    }
+
+    let span = node.span();
+    let offset = cursor - node.offset();
+    for (i, frame) in frames.iter().enumerate() {
+        if let Some((point, height)) = caret_in_frame(frame, span, offset) {
+            return Some(Caret {
+                page: NonZeroUsize::new(i + 1).unwrap(),
+                point,
+                height,
+            });
+        }
+    }
+
+    None
+}
+
+/// Find the caret for an offset in a span in a frame.
+fn caret_in_frame(frame: &Frame, span: Span, offset: usize) -> Option<(Point, Abs)> {
+    frame.flat_items().find_map(|(ts, item)| {
+        let FrameItem::Text(text) = item else { return None };
+        let x = caret_in_text(text, span, offset)?;
+        let metrics = text.font.metrics();
+        let top = Point::new(x, -metrics.ascender.at(text.size)).transform(ts);
+        let bottom = Point::new(x, -metrics.descender.at(text.size)).transform(ts);
+        Some((top, (bottom - top).hypot()))
+    })
+}
+
+/// Find the horizontal caret position for an offset in a span in a text run.
+fn caret_in_text(text: &TextItem, span: Span, offset: usize) -> Option<Abs> {
+    let rtl = text.dir == Dir::RTL;
+    let mut x = Abs::zero();
+    for glyph in &text.glyphs {
+        let width = glyph.x_advance.at(text.size);
+        if glyph.span == span {
+            let start = usize::from(glyph.offset);
+            let (leading, trailing) = if rtl { (x + width, x) } else { (x, x + width) };
+            if start == offset {
+                return Some(leading);
+            } else if start < offset && offset <= start + glyph.c.len_utf8() {
+                return Some(trailing);
+            }
+        }
+        x += width;
+    }
+
+    None
+}
+
+/// A link in the document together with the area it covers.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LinkArea {
+    /// The page the link is on.
+    pub page: NonZeroUsize,
+    /// The top-left corner of the link's bounding box on the page.
+    pub point: Point,
+    /// The size of the link's bounding box.
+    pub size: Size,
+    /// Where the link leads to.
+    pub target: Jump,
+}
+
+/// Find all links in the document along with their areas on the pages.
+///
+/// The areas are the axis-aligned bounding boxes of the links in page
+/// coordinates, that is, with all transformations of enclosing groups applied.
+pub fn link_areas(frames: &[Frame]) -> Vec<LinkArea> {
+    let mut introspector = None;
+    let mut areas = vec![];
+    for (i, frame) in frames.iter().enumerate() {
+        let page = NonZeroUsize::new(1 + i).unwrap();
+        for (ts, item) in frame.flat_items() {
+            let FrameItem::Meta(Meta::Link(dest), size) = item else { continue };
+            let target = match dest {
+                Destination::Url(url) => Jump::Url(url.clone()),
+                Destination::Position(pos) => Jump::Position(*pos),
+                Destination::Location(loc) => Jump::Position(
+                    introspector
+                        .get_or_insert_with(|| Introspector::new(frames))
+                        .position(*loc),
+                ),
+            };
+
+            // Compute the bounding box of the transformed link.
+            let corners = [
+                Point::zero(),
+                Point::with_x(size.x),
+                Point::with_y(size.y),
+                size.to_point(),
+            ]
+            .map(|point| point.transform(ts));
+            let min = corners.into_iter().reduce(Point::min).unwrap();
+            let max = corners.into_iter().reduce(Point::max).unwrap();
+
+            areas.push(LinkArea {
+                page,
+                point: min,
+                size: (max - min).to_size(),
+                target,
+            });
+        }
+    }
+    areas
+}
+
 /// Find the position of a span in a frame.
 fn find_in_frame(frame: &Frame, span: Span) -> Option<Point> {
     for (mut pos, item) in frame.items() {
@@ -169,3 +301,96 @@ fn is_in_rect(pos: Point, size: Size, click: Point) -> bool {
         && pos.y <= click.y
         && pos.y + size.y >= click.y
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::tests::font;
+    use crate::geom::{Abs, Ratio, Transform};
+
+    #[test]
+    fn test_caret_from_cursor() {
+        let font = font();
+        let size = Abs::pt(10.0);
+        let ascent = font.metrics().ascender.at(size);
+        let descent = -font.metrics().descender.at(size);
+
+        let source = Source::detached("Hello");
+        let span = LinkedNode::new(source.root()).leaf_at(1).unwrap().span();
+
+        // A run of glyphs with the given offsets.
+        let text = |dir, offsets: &[u16]| {
+            let glyphs: Vec<_> =
+                offsets.iter().map(|&offset| ('a', span, offset)).collect();
+            crate::doc::tests::text(&font, dir, &glyphs)
+        };
+
+        let caret = |item, cursor| {
+            let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+            page.push(Point::new(Abs::pt(10.0), Abs::pt(20.0)), item);
+            caret_from_cursor(&[page], &source, cursor).unwrap()
+        };
+
+        let check = |caret: Caret, x: f64, y: Abs, height: Abs| {
+            assert!(caret.point.x.approx_eq(Abs::pt(x)), "{caret:?}");
+            assert!(caret.point.y.approx_eq(y), "{caret:?}");
+            assert!(caret.height.approx_eq(height), "{caret:?}");
+        };
+
+        let top = Abs::pt(20.0) - ascent;
+        let height = ascent + descent;
+
+        // Left-to-right text.
+        check(caret(text(Dir::LTR, &[0, 1, 2, 3, 4]), 2), 20.0, top, height);
+        check(caret(text(Dir::LTR, &[0, 1, 2, 3, 4]), 5), 35.0, top, height);
+
+        // Right-to-left text, where the glyphs are in visual order.
+        check(caret(text(Dir::RTL, &[4, 3, 2, 1, 0]), 2), 25.0, top, height);
+        check(caret(text(Dir::RTL, &[0]), 0), 15.0, top, height);
+        check(caret(text(Dir::RTL, &[0]), 1), 10.0, top, height);
+
+        // Text in a scaled group.
+        let mut inner = Frame::new(Size::splat(Abs::pt(40.0)));
+        inner.push(Point::with_y(Abs::pt(10.0)), text(Dir::LTR, &[0, 1, 2, 3, 4]));
+        inner.transform(Transform::scale(Ratio::new(2.0), Ratio::new(2.0)));
+        let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+        page.push_frame(Point::new(Abs::pt(10.0), Abs::pt(20.0)), inner);
+        let caret = caret_from_cursor(&[page], &source, 2).unwrap();
+        check(caret, 30.0, Abs::pt(40.0) - 2.0 * ascent, 2.0 * height);
+    }
+
+    #[test]
+    fn test_link_areas() {
+        let url = || Destination::Url("https://typst.app".into());
+        let link = |size| FrameItem::Meta(Meta::Link(url()), size);
+
+        let mut inner = Frame::new(Size::splat(Abs::pt(40.0)));
+        inner.push(Point::splat(Abs::pt(5.0)), link(Size::splat(Abs::pt(10.0))));
+        inner.transform(Transform::scale(Ratio::new(2.0), Ratio::new(2.0)));
+
+        let mut page = Frame::new(Size::splat(Abs::pt(100.0)));
+        page.push(Point::zero(), link(Size::new(Abs::pt(20.0), Abs::pt(5.0))));
+        page.push_frame(Point::new(Abs::pt(10.0), Abs::pt(20.0)), inner);
+
+        let areas = link_areas(&[Frame::new(Size::zero()), page]);
+        let page = NonZeroUsize::new(2).unwrap();
+        let target = Jump::Url("https://typst.app".into());
+        assert_eq!(
+            areas,
+            [
+                LinkArea {
+                    page,
+                    point: Point::zero(),
+                    size: Size::new(Abs::pt(20.0), Abs::pt(5.0)),
+                    target: target.clone(),
+                },
+                LinkArea {
+                    page,
+                    point: Point::new(Abs::pt(20.0), Abs::pt(30.0)),
+                    size: Size::splat(Abs::pt(20.0)),
+                    target,
+                },
+            ]
+        );
+    }
+}
@@ -22,6 +22,11 @@ use crate::eval::Tracer;
 use crate::World;
 
 /// Typeset content into a fully layouted document.
+///
+/// Layout results are memoized and the document is layouted multiple times
+/// until all introspections stabilize. Intermediate frames are thus neither
+/// produced in order nor final. To process pages as they are finished, iterate
+/// over the pages of the returned document instead.
 #[comemo::memoize]
 pub fn typeset(
     world: Tracked<dyn World>,
@@ -33,6 +33,26 @@
 #align(start)[يبدأ]
 #align(end)[نهاية]
 
+---
+// Test that start and end map to the physical edges according to the text
+// direction.
+// Ref: false
+#let sq = box(width: 10pt, height: 10pt, fill: black)
+#align(start)[#sq <ltr-start>]
+#align(left)[#sq <ltr-left>]
+#set text(dir: rtl)
+#align(start)[#sq <rtl-start>]
+#align(right)[#sq <rtl-right>]
+#align(end)[#sq <rtl-end>]
+#align(left)[#sq <rtl-left>]
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<ltr-start>), x(<ltr-left>))
+  test(x(<rtl-start>), x(<rtl-right>))
+  test(x(<rtl-end>), x(<rtl-left>))
+  test(x(<rtl-start>) > x(<rtl-end>), true)
+})
+
 ---
 // Ref: false
 #test(type(center), "alignment")
@@ -14,3 +14,89 @@
   fill: aqua,
   lorem(8) + colbreak(),
 )
+
+---
+// Test that containers expand along each axis independently.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let natural = size[Hello]
+  for container in (box, block, block.with(breakable: false)) {
+    test(size(container[Hello]), natural)
+    test(
+      size(container(width: 50pt)[Hello]),
+      (width: 50pt, height: natural.height),
+    )
+    test(
+      size(container(height: 50pt)[Hello]),
+      (width: natural.width, height: 50pt),
+    )
+    test(
+      size(container(width: 50pt, height: 50pt)[Hello]),
+      (width: 50pt, height: 50pt),
+    )
+  }
+})
+
+---
+// Ref: false
+// Test that an unbreakable block moves to the next page as a whole.
+#set page(height: 60pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt)
+#set block(spacing: 0pt)
+#block(height: 20pt)
+#block(breakable: false)[*1* <c1> \ *2* \ *3* \ *4* <c4>]
+
+#locate(loc => {
+  test(query(<c1>, loc).first().location().page(), 2)
+  test(query(<c4>, loc).first().location().page(), 2)
+})
+
+---
+// Ref: false
+// Test deriving a box's size from its aspect ratio.
+#style(styles => {
+  let size(..args) = measure(box(..args, rect(width: 20pt, height: 10pt)), styles)
+  test(size(width: 40pt, aspect: 2), (width: 40pt, height: 20pt))
+  test(size(height: 40pt, aspect: 0.5), (width: 20pt, height: 40pt))
+  test(size(width: 40pt, height: 5pt, aspect: 2), (width: 40pt, height: 5pt))
+  test(size(aspect: 1), (width: 20pt, height: 20pt))
+  test(size(aspect: 4), (width: 40pt, height: 10pt))
+})
+
+---
+// Error: 14-16 aspect ratio must be positive
+#box(aspect: -1)
+
+---
+// Ref: false
+// Test clamping the size of automatically sized boxes.
+#style(styles => {
+  let size(..args) = measure(box(..args, rect(width: 20pt, height: 10pt)), styles)
+  test(size(min-width: 30pt), (width: 30pt, height: 10pt))
+  test(size(min-width: 10pt), (width: 20pt, height: 10pt))
+  test(size(max-height: 5pt), (width: 20pt, height: 5pt))
+  test(size(min-height: 15pt, max-height: 25pt), (width: 20pt, height: 15pt))
+  test(size(width: 10pt, min-width: 30pt), (width: 10pt, height: 10pt))
+})
+
+---
+// Ref: false
+// Test that the maximum width limits the space for the content.
+#style(styles => {
+  let limited = measure(box(max-width: 40pt, lorem(10)), styles)
+  test(limited.width <= 40pt, true)
+  test(limited.height > measure(box(lorem(10)), styles).height, true)
+})
+
+---
+// Ref: false
+// Test that relative constraints are ignored without a finite base.
+#style(styles => {
+  let size(..args) = measure(box(..args, rect(width: 20pt, height: 10pt)), styles)
+  test(size(min-width: 50%), (width: 20pt, height: 10pt))
+  test(size(max-height: 50%), (width: 20pt, height: 10pt))
+  test(size(min-width: 50% + 30pt), (width: 20pt, height: 10pt))
+  test(size(max-width: 0% + 10pt), (width: 10pt, height: 10pt))
+})
@@ -0,0 +1,14 @@
+// Test clipping with the `clip` argument of boxes and blocks.
+// Ref: false
+
+---
+// Clipping doesn't change the size of a container.
+#style(styles => {
+  let body = rect(width: 50pt, height: 50pt)
+  for container in (box, block) {
+    test(
+      measure(container(width: 20pt, height: 10pt, clip: true, body), styles),
+      (width: 20pt, height: 10pt),
+    )
+  }
+})
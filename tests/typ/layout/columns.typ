@@ -103,3 +103,45 @@ This is a normal page. Very normal.
 // Test a page with zero columns.
 // Error: 49-50 number must be positive
 #set page(height: auto, width: 7.05cm, columns: 0)
+
+---
+// Test column balancing.
+// Ref: false
+#style(styles => {
+  let body = lorem(20)
+  let plain = measure(block(width: 100pt, columns(2, body)), styles)
+  let balanced = measure(
+    block(width: 100pt, columns(2, balance: true, body)),
+    styles,
+  )
+  test(balanced.height < plain.height, true)
+  test(balanced.height > plain.height / 3, true)
+})
+
+---
+// Test that unbalanced columns put everything into the first column if it
+// fits.
+// Ref: false
+#style(styles => {
+  let body = lorem(20)
+  let plain = measure(block(width: 100pt, columns(2, body)), styles)
+  let single = measure(block(width: 48pt, body), styles)
+  test(plain.height, single.height)
+})
+
+---
+// Test that balancing keeps the layout if the content can't be shortened.
+// Ref: false
+#set page(width: 120pt, height: 100pt, margin: 10pt)
+#let body = [#block(width: 100%, height: 120pt, breakable: false)[A] <a>]
+#columns(2, body)
+#pagebreak()
+#columns(2, balance: true, body)
+
+#locate(loc => {
+  let pos = query(<a>, loc).map(elem => elem.location().position())
+  test(pos.len(), 2)
+  test(pos.at(1).page, pos.at(0).page + 1)
+  test(pos.at(1).x, pos.at(0).x)
+  test(pos.at(1).y, pos.at(0).y)
+})
@@ -28,3 +28,31 @@ This is the start and it goes on.
 // All three lines go to the next page.
 #set text(olive)
 #lorem(10)
+
+---
+// Ref: false
+// Test that more lines can be kept together at the end.
+#set page(height: 60pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, widows: 3)
+*1* <a1> \ *2* <a2> \ *3* <a3> \ *4* <a4> \ *5* <a5>
+
+#locate(loc => {
+  test(query(<a2>, loc).first().location().page(), 1)
+  test(query(<a3>, loc).first().location().page(), 2)
+})
+
+---
+// Ref: false
+// Test that a single line may be left behind at the bottom.
+#set page(height: 60pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt, orphans: 1)
+#set block(spacing: 0pt)
+#block(height: 30pt)
+*1* <b1> \ *2* <b2> \ *3* <b3>
+
+#locate(loc => {
+  test(query(<b1>, loc).first().location().page(), 1)
+  test(query(<b2>, loc).first().location().page(), 2)
+})
@@ -39,3 +39,12 @@
   align(center)[A bit more to the top],
   [],
 )
+
+---
+// Test that cells fill the grid row by row and a partial last row is fine.
+// Ref: false
+#style(styles => {
+  let cell = box(width: 5pt, height: 10pt)
+  let size = measure(grid(columns: 3, ..range(7).map(i => cell)), styles)
+  test(size, (width: 15pt, height: 30pt))
+})
@@ -10,3 +10,17 @@
   [Hello there, my friends! Hi!],
   [Hello there, my friends! Hi! What is going on right now?],
 )
+
+---
+// Test that only auto columns wider than their fair share are shrunk.
+// Ref: false
+#style(styles => {
+  let height(..args) = measure(block(width: 100pt, grid(..args)), styles).height
+  let short = box(width: 10pt)
+  let long = lorem(20)
+  test(height(columns: 2, short, long), height(columns: (10pt, 90pt), short, long))
+  test(
+    height(columns: 3, short, long, long),
+    height(columns: (10pt, 45pt, 45pt), short, long, long),
+  )
+})
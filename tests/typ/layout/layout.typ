@@ -0,0 +1,21 @@
+// Test the `layout` function.
+// Ref: false
+
+---
+// Test the remaining space on a page.
+#set page(height: 100pt)
+#set block(spacing: 10pt)
+#layout(size => test(size, (width: 100pt, height: 80pt)))
+#block(height: 30pt)
+#layout(size => test(size, (width: 100pt, height: 30pt)))
+
+---
+// Test the remaining space in a column.
+#set page(height: 100pt)
+#columns(2, gutter: 10pt)[
+  #layout(size => test(size.width, 45pt))
+]
+
+---
+// Test remaining space on a page with auto height.
+#layout(size => test(size.height > 1000000pt, true))
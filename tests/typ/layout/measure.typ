@@ -0,0 +1,18 @@
+// Test sizing content relative to measured content.
+// Ref: false
+
+---
+#let title = [*Typst Quarterly*]
+#style(styles => {
+  let width = measure(title, styles).width
+  test(measure(line(length: width), styles).width, width)
+  test(measure(rect(width: 2 * width), styles).width, 2 * width)
+})
+
+---
+// Test measuring a labelled element.
+*Title* <title>
+#locate(loc => style(styles => {
+  let title = query(<title>, loc).first()
+  test(measure(title, styles).width, measure([*Title*], styles).width)
+}))
@@ -22,3 +22,18 @@ answer it.
 
 #set page(header: none, height: auto, margin: (top: 15pt, bottom: 25pt))
 The END.
+
+---
+// Test giving the first page its own style.
+// Ref: false
+#page(margin: 0pt, header: none)[*Title* <title>]
+#set page(margin: 10pt, header: [Header])
+*Body* <body>
+#locate(loc => {
+  let title = query(<title>, loc).first().location()
+  let body = query(<body>, loc).first().location()
+  test(title.page(), 1)
+  test(title.position().x, 0pt)
+  test(body.page(), 2)
+  test(body.position().x, 10pt)
+})
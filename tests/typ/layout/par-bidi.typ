@@ -53,3 +53,32 @@ Lריווח #h(1cm) R
 
 // Error: 16-19 text direction must be horizontal
 #set text(dir: ttb)
+
+---
+// Test isolating content of unknown direction.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width[A #isolate[B] C], width[A B C])
+  test(width[A #isolate(dir: rtl)[B] C], width[A B C])
+})
+
+---
+// Test that an isolate keeps trailing punctuation with its content. Without
+// the isolate, the exclamation mark takes the paragraph's direction and ends
+// up on the left of the name.
+// Ref: false
+#set text(dir: rtl)
+שלום *Alice* <a1>*!* <e1> עולם
+
+שלום #isolate[*Alice* <a2>*!* <e2>] עולם
+
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<e1>) < x(<a1>), true)
+  test(x(<e2>) > x(<a2>), true)
+})
+
+---
+// Error: 15-18 text direction must be horizontal
+#isolate(dir: ttb)[A]
@@ -50,3 +50,16 @@ Welcome \ here. Does this work well?
 #set text(dir: rtl)
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
+
+---
+// Test that the hanging indent only applies to lines after the first.
+// Ref: false
+#set par(hanging-indent: 15pt)
+*A* <first> \
+*B* <second> \
+*C* <third>
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<second>) - x(<first>), 15pt)
+  test(x(<third>), x(<second>))
+})
@@ -31,3 +31,15 @@ D E F #linebreak(justify: true)
 // basically empty paragraph.
 #set par(justify: true)
 #""
+
+---
+// Test that lines ending in a regular line break are not stretched.
+// Ref: false
+#set par(justify: true)
+A B *C* <justified> \
+D E F
+#par(justify: false)[A B *C* <ragged>]
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<justified>), x(<ragged>))
+})
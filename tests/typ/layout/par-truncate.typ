@@ -0,0 +1,58 @@
+// Test paragraph truncation.
+// Ref: false
+
+---
+// Text that does not fit is cut off instead of wrapping.
+#style(styles => {
+  let line = measure(block(width: 100pt)[Hello], styles)
+  let cut = measure(block(width: 100pt, par(truncate: true, lorem(20))), styles)
+  test(cut.height, line.height)
+})
+
+---
+// A single word that is too long is cut off as well.
+#style(styles => {
+  let word = "Supercalifragilisticexpialidocious"
+  let size(body) = measure(box(body), styles)
+  let cut = size(block(width: 60pt, par(truncate: true, word)))
+  test(cut.width <= 60pt, true)
+  test(cut.height, size[Hello].height)
+})
+
+---
+// Text that fits is unaffected.
+#style(styles => {
+  let size(body) = measure(body, styles)
+  test(size(par(truncate: true)[Hello]), size[Hello])
+})
+
+---
+// Forced line breaks end the paragraph, too.
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let cut = size(par(truncate: true, ellipsis: none)[Hello \ World])
+  test(cut, size[Hello])
+})
+
+---
+// Test limiting the number of lines.
+#style(styles => {
+  let size(body) = measure(block(width: 100pt, body), styles)
+  let two = size[A \ B]
+  test(size(par(max-lines: 2, lorem(30))).height, two.height)
+  test(size(par(max-lines: 2, truncate: true, lorem(30))).height, two.height)
+  test(size(par(max-lines: 3)[A \ B]).height, two.height)
+})
+
+---
+// The last remaining line isn't stretched under justification, so the words
+// in it are spaced as in ragged text.
+#set block(width: 100pt)
+#block(par(justify: true, max-lines: 1)[A *B* <b> #lorem(20)])
+#block(par(justify: true, truncate: true)[A *B* <b> #lorem(20)])
+#block(par(max-lines: 1)[A *B* <b> #lorem(20)])
+#locate(loc => {
+  let xs = query(<b>, loc).map(b => b.location().position().x)
+  test(xs.at(0), xs.at(2))
+  test(xs.at(1), xs.at(2))
+})
@@ -34,3 +34,12 @@ fn main() {}
 - List
 
 Paragraph
+
+---
+// Test that content returned from a function flows piece by piece.
+// Ref: false
+#let pieces(n) = range(n).map(_ => box(width: 10pt, height: 10pt)).join([ ])
+#style(styles => {
+  let height(body) = measure(block(width: 25pt, body), styles).height
+  test(height(pieces(4)), height[#pieces(2) \ #pieces(2)])
+})
@@ -38,6 +38,36 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 #set text(dir: rtl)
 ريجين#box(width: 1fr, repeat(rect(width: 4em, height: 0.7em)))سون
 
+---
+// Test repeat with and without justification.
+// Ref: false
+#set text(size: 10pt, number-width: "tabular")
+#let leader(justify) = align(end, repeat(justify: justify)[#box(width: 5pt)[.] <d>])
+Intro #box(width: 1fr, leader(false)) <b>#box[12] <n> \
+Outlook #box(width: 1fr, leader(false)) <b>#box[17] <n> \
+Intro #box(width: 1fr, leader(true)) <b>#box[12] <n> \
+Outlook #box(width: 1fr, leader(true)) <b>#box[17] <n>
+
+#locate(loc => {
+  let x(label) = query(label, loc).map(elem => elem.location().position().x)
+  let boxes = x(<b>)
+  let dots = x(<d>)
+  let ends = x(<n>)
+  test(ends.at(0), ends.at(1))
+
+  // Without justification, whole dots fill the leader up to its end.
+  for i in (0, 1) {
+    let count = (ends.at(i) - dots.at(i)) / 5pt
+    test(calc.abs(count - calc.round(count)) < 1e-6, true)
+    test(dots.at(i) > boxes.at(i), true)
+  }
+
+  // With justification, the dots span the whole leader.
+  for i in (2, 3) {
+    test(dots.at(i), boxes.at(i))
+  }
+})
+
 ---
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
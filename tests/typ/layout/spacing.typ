@@ -30,6 +30,28 @@ A #h(-1fr) B
 A #h(10pt) B \
 A #h(1fr) B
 
+---
+// Test how adjacent spacings combine.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width[A#h(5pt)#h(5pt)B], width[A#h(10pt)B])
+  test(width[A#h(5pt, weak: true)#h(8pt, weak: true)B], width[A#h(8pt)B])
+  test(width[A#h(8pt, weak: true)#h(5pt, weak: true)B], width[A#h(8pt)B])
+  test(width[A#h(5pt)#h(8pt, weak: true)B], width[A#h(13pt)B])
+  test(width[#h(5pt, weak: true)A#h(5pt, weak: true)], width[A])
+})
+
+---
+// Weak spacing collapses at the edges of containers.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(block(body), styles)
+  test(size[#v(10pt, weak: true) A #v(10pt, weak: true)], size[A])
+  test(size[#h(10pt, weak: true) A #h(10pt, weak: true)], size[A])
+  test(size[#v(10pt) A], size[#v(10pt, weak: true) #v(10pt) A])
+})
+
 ---
 // Missing spacing.
 // Error: 11-13 missing argument: amount
@@ -50,3 +50,15 @@
   align(left, [B]),
   [C],
 )
+
+---
+// Test that nested stacks with different directions keep their spacing apart.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let sq = box(width: 10pt, height: 10pt)
+  let inner = stack(dir: ttb, spacing: 3pt, sq, 2pt, sq, sq)
+  test(size(inner), (width: 10pt, height: 35pt))
+  test(size(stack(dir: ltr, spacing: 5pt, sq, inner, 1pt, sq)), (width: 36pt, height: 35pt))
+  test(size(stack(dir: rtl, spacing: 5pt, inner, stack(dir: btt, sq, sq))), (width: 25pt, height: 35pt))
+})
@@ -47,3 +47,59 @@ nor #xetex!
 #box(scale(r, x: 50%, y: 200%, origin: left + top))
 #box(scale(r, x: 50%, origin: center))
 #box(scale(r, x: 50%, y: 200%, origin: right + bottom))
+
+---
+// Scaling does not affect the layouted size.
+// Ref: false
+#style(styles => {
+  let r = rect(width: 20pt, height: 10pt)
+  test(measure(scale(200%, r), styles), measure(r, styles))
+})
+
+---
+// Ref: false
+// Test that reflowed rotation adjusts the bounding box. The sines and cosines
+// aren't exact, so the sizes are compared in rounded points.
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  test(measure(rotate(90deg, body), styles), (width: 20pt, height: 10pt))
+
+  let quarter = measure(rotate(90deg, reflow: true, body), styles)
+  test(calc.round(quarter.width / 1pt, digits: 6), 10.0)
+  test(calc.round(quarter.height / 1pt, digits: 6), 20.0)
+
+  let half = measure(rotate(180deg, reflow: true, body), styles)
+  test(calc.round(half.width / 1pt, digits: 6), 20.0)
+
+  // At 45 degrees, both sides contribute equally to the width.
+  let diagonal = measure(rotate(45deg, reflow: true, body), styles)
+  test(calc.round(diagonal.width / 1pt, digits: 6), calc.round(30 / calc.sqrt(2), digits: 6))
+})
+
+---
+// Ref: false
+// Test that text rotated with reflow sits on its bottom edge like any other
+// box.
+#style(styles => {
+  let body = rotate(90deg, reflow: true)[World]
+  let size = measure(body, styles)
+  let filler = rect(width: size.width, height: size.height, stroke: none)
+  [A #box(body) <r> #box(filler) <s>]
+})
+
+#locate(loc => {
+  let y(label) = query(label, loc).first().location().position().y
+  test(y(<r>), y(<s>))
+})
+
+---
+// Ref: false
+// Test that reflowed scaling adjusts the bounding box.
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  let size(..args) = measure(scale(..args, body), styles)
+  test(size(200%), (width: 20pt, height: 10pt))
+  test(size(200%, reflow: true), (width: 40pt, height: 20pt))
+  test(size(x: 50%, reflow: true), (width: 10pt, height: 10pt))
+  test(size(x: -100%, reflow: true), (width: 20pt, height: 10pt))
+})
@@ -51,3 +51,42 @@ multiline.
 =
 Not in heading
 =Nope
+
+---
+// The spacing below a heading takes precedence over the spacing above a
+// following paragraph and the paragraph is not indented.
+// Ref: false
+#let body = [
+  = Heading
+  A paragraph with a bit more text.
+]
+
+#style(styles => {
+  let plain = measure(body, styles)
+  let spaced = measure({
+    set block(spacing: 100pt)
+    body
+  }, styles)
+  let indented = measure({
+    set par(first-line-indent: 50pt)
+    body
+  }, styles)
+  test(spaced.height, plain.height)
+  test(indented.width, plain.width)
+})
+
+---
+// Test that a heading is never the last thing on a page and moves to the next
+// page together with the first line of the following paragraph.
+// Ref: false
+#set page(height: 60pt)
+#set text(5pt, top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 0pt)
+#block(height: 15pt)
+= Heading <h>
+*A* <a> \ *B*
+
+#locate(loc => {
+  test(query(<h>, loc).first().location().page(), 2)
+  test(query(<a>, loc).first().location().page(), 2)
+})
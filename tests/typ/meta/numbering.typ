@@ -16,3 +16,13 @@
 ---
 // Error: 17-19 number must be positive
 #numbering("1", -1)
+
+---
+// Ref: false
+#test(numbering("1", 3), "3")
+#test(numbering("i", 4), "iv")
+#test(numbering("I", 14), "XIV")
+#test(numbering("A", 3), "C")
+#test(numbering("(1)", 7), "(7)")
+#test(numbering("1.a", 2, 1), "2.a")
+#test(numbering("1.", 1, 2), "1.2.")
@@ -68,3 +68,16 @@
   image("/tiger.jpg"),
   caption: [Tiger world],
 )
+
+---
+// A heading at the top of a page is not yet visible in that page's header.
+// Ref: false
+#set page(height: 60pt, header: locate(loc => {
+  if loc.page() == 2 {
+    test(query(heading, before: loc).len(), 1)
+  }
+}))
+
+= First
+#pagebreak()
+= Second
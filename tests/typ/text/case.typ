@@ -1,4 +1,4 @@
-// Test the `upper` and `lower` functions.
+// Test the `upper`, `lower`, and `titlecase` functions.
 // Ref: false
 
 ---
@@ -6,6 +6,20 @@
 #test(lower(memes), "are memes great?")
 #test(upper(memes), "ARE MEMES GREAT?")
 #test(upper("Ελλάδα"), "ΕΛΛΆΔΑ")
+#test(lower("ΟΔΟΣ ΣΟΦΟΣ"), "οδος σοφος")
+#test(titlecase("the end's near-ish"), "The End's Near-Ish")
+#test(titlecase("iPhone über alles"), "IPhone Über Alles")
+
+---
+// Test language-specific and style-spanning case changes.
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(text(lang: "tr", upper[izmir])), width[İZMİR])
+  test(width(text(lang: "tr", lower[ISPARTA])), width[ısparta])
+  test(width(text(lang: "en", upper[izmir])), width[IZMIR])
+  test(width(lower[ΟΔΟΣ]), width[οδος])
+  test(width(titlecase[hel*lo* world]), width[Hel*lo* World])
+})
 
 ---
 // Error: 8-9 expected string or content, found integer
@@ -9,6 +9,26 @@
 // Test smallcaps.
 #smallcaps[Smallcaps]
 
+---
+// Test synthesized smallcaps for a font without the feature.
+// Ref: false
+#set text(font: "PT Sans")
+#style(styles => {
+  let a = measure(smallcaps[Small caps], styles)
+  let b = measure([S#text(0.75em)[MALL] #text(0.75em)[CAPS]], styles)
+  test(a.width, b.width)
+})
+
+---
+// Test that synthesized smallcaps use the language's case mapping.
+// Ref: false
+#set text(font: "PT Sans", lang: "tr")
+#style(styles => {
+  let a = measure(smallcaps[iz], styles)
+  let b = measure(text(0.75em)[İZ], styles)
+  test(a.width, b.width)
+})
+
 ---
 // Test alternates and stylistic sets.
 #set text(font: "IBM Plex Serif")
@@ -50,6 +70,32 @@ fi vs. #text(features: (liga: 0))[No fi]
 // Error: 26-28 stylistic set must be between 1 and 20
 #set text(stylistic-set: 25)
 
+---
+// Test that tabular digits are of equal width, even without font support.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  for font in ("Linux Libertine", "PT Sans", "Inria Serif") {
+    let tab = text.with(font: font, number-width: "tabular")
+    test(width(tab[1]), width(tab[8]))
+    test(width(tab[111]), width(tab[808]))
+    test(width(tab[i]), width(text(font: font)[i]))
+  }
+
+  // Inria Serif has proportional default digits and narrower tabular ones,
+  // which must not be padded to the widest default digit.
+  let inria = text.with(font: "Inria Serif")
+  test(width(inria[1]) < width(inria[0]), true)
+  test(width(inria(number-width: "tabular")[1]) < width(inria[0]), true)
+
+  // The test font is Inria Serif without its tnum feature, so its digits are
+  // padded to the width of the widest one, the zero.
+  let padded = text.with(font: "No Tnum Test")
+  test(width(padded[1]) < width(padded[0]), true)
+  test(width(padded(number-width: "tabular")[1]), width(padded[0]))
+  test(width(padded(number-width: "tabular")[1789]), 4 * width(padded[0]))
+})
+
 ---
 // Error: 24-25 expected "lining", "old-style", or auto, found integer
 #set text(number-type: 2)
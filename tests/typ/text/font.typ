@@ -43,6 +43,23 @@ Emoji: 🐪, 🌋, 🏞
 #text([Text], teal, font: "IBM Plex Serif") \
 #text(forest, font: "New Computer Modern", [Text]) \
 
+---
+// Test per-script fonts.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let latn = text.with(script-fonts: (latn: "IBM Plex Serif"))
+  test(width(latn[Hello]), width(text(font: "IBM Plex Serif")[Hello]))
+  test(width(latn[Hello]) != width[Hello], true)
+  test(width(latn(text(script-fonts: (arab: "Noto Sans Arabic"))[Hello])), width(latn[Hello]))
+  test(width(text(script-fonts: (latn: "PT Sans"))[Hello]), width(text(font: "PT Sans")[Hello]))
+  test(width(text(script-fonts: (arab: "PT Sans"))[Hello]), width[Hello])
+})
+
+---
+// Error: 25-40 expected ISO 15924 script code
+#set text(script-fonts: (abcd: "Arial"))
+
 ---
 // Error: 11-16 unexpected argument
 #set text(false)
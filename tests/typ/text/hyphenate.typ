@@ -41,6 +41,31 @@ It's a #emph[Tree]beard.
   [Bar-?ankauf],
 )
 
+---
+// Test that hyphenation respects the minimum number of letters at the start
+// and end of a word.
+// Ref: false
+#set text(lang: "en", hyphenate: true)
+#style(styles => {
+  let height(body) = measure(block(width: 1pt, body), styles).height
+  let one = height[x]
+  test(height[over], one)
+  test(height[hyphenation] > one, true)
+})
+
+---
+// Test that shy hyphens work without automatic hyphenation and are invisible
+// unless the line breaks there.
+// Ref: false
+#set text(hyphenate: false)
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let height(body) = measure(block(width: 30pt, body), styles).height
+  test(width[Bar-?ankauf], width[Barankauf])
+  test(height[Bar-?ankauf] > height[Barankauf], true)
+  test(height[Bar-?ankauf], height[Bar- \ ankauf])
+})
+
 ---
 // This sequence would confuse hypher if we passed trailing / leading
 // punctuation instead of just the words. So this tests that we don't
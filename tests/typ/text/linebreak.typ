@@ -34,3 +34,35 @@ With a soft #linebreak(justify: true)
 break you can force a break without #linebreak(justify: true)
 breaking justification. #linebreak(justify: false)
 Nice!
+
+---
+// Test adding and removing break opportunities.
+// Ref: false
+#style(styles => {
+  let lines(body) = measure(block(width: 1pt, body), styles).height
+  let one = lines[AAA]
+  test(lines[AAA\u{200B}BBB] > one, true)
+  test(lines[AAA-BBB] > one, true)
+  test(lines[AAA-\u{2060}BBB], one)
+})
+
+---
+// Test that empty lines are as high as lines with text.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  test(height[A \ \ B], height[A \ X \ B])
+  test(height[A \ \ \ B], height[A \ X \ X \ B])
+  test(height(text(top-edge: 1em)[A \ \ B]), height(text(top-edge: 1em)[A \ X \ B]))
+})
+
+---
+// Test Unicode line break classes.
+// Ref: false
+#style(styles => {
+  let lines(body) = measure(block(width: 1pt, body), styles).height
+  let one = lines[A]
+  test(lines[(12.5)], one)
+  test(lines[A B] > one, true)
+  test(lines[中文], lines[中 文])
+})
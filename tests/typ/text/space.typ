@@ -40,3 +40,53 @@ A#"\n" B
 ---
 // Test that trailing space does not force a line break.
 LLLLLLLLLLLLLLLLLL R _L_
+
+---
+// Test that visible whitespace does not affect the layout.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let body = [A B~C \ #lorem(10)]
+  test(size(text(visible-whitespace: true, body)), size(body))
+})
+
+---
+// Test that tabs in markup collapse into a single space, also when
+// justifying.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width[A	B], width[A B])
+  test(width[A 	 	B], width[A B])
+  let height(body) = measure(block(width: 50pt, par(justify: true, body)), styles).height
+  test(height[#lorem(10)	#lorem(10)], height[#lorem(10) #lorem(10)])
+})
+
+---
+// Test Unicode space and control characters. Linux Libertine's advances are
+// multiples of 1/2048 em, so the widths add up exactly.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let lines(body) = measure(block(width: 1pt, body), styles).height
+
+  // A non-breaking space is as wide as a normal one, but doesn't break.
+  test(width[1~2], width[1 2])
+  test(lines[1~2], lines[1])
+  test(lines[1 2] > lines[1], true)
+
+  // Word spacing stretches normal spaces, but not en, em and thin spaces.
+  test(width(text(spacing: 300%)[1 2]) > width[1 2], true)
+  for space in ("\u{2002}", "\u{2003}", "\u{2009}") {
+    test(width(text(spacing: 300%)[1#(space)2]), width[1#(space)2])
+  }
+
+  // Zero-width characters take no space. Only the zero-width space allows a
+  // break.
+  for c in ("\u{200B}", "\u{200C}", "\u{200D}") {
+    test(width[1#(c)2], width[12])
+  }
+  test(lines[1\u{200B}2] > lines[1], true)
+  test(lines[1\u{200C}2], lines[1])
+  test(lines[1\u{200D}2], lines[1])
+})
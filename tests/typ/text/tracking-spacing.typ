@@ -29,3 +29,15 @@ My text has spaces.
 // Test word spacing relative to the font's space width.
 #set text(spacing: 50% + 1pt)
 This is tight.
+
+---
+// Ref: false
+// Test that tracking is only added between characters and not after the last
+// one of a line. At 8pt, the tracking is exactly a quarter em, so the widths
+// add up exactly.
+#set text(8pt)
+#style(styles => {
+  let tracked = measure(text(tracking: 2pt)[abc], styles).width
+  test(tracked, measure([abc], styles).width + 4pt)
+  test(measure(text(tracking: 2pt)[abc \ d], styles).width, tracked)
+})
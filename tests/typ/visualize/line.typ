@@ -49,3 +49,29 @@
 ---
 // Error: 14-26 expected relative length, found angle
 #line(start: (3deg, 10pt), length: 5cm)
+
+---
+// Ref: false
+// Test dashed strokes.
+#line(length: 100%, stroke: (dash: "dotted"))
+#line(length: 100%, stroke: (paint: red, thickness: 2pt, dash: "dash-dotted"))
+#rect(stroke: (rest: (thickness: 1pt, dash: "dashed")))
+#rect(stroke: (left: (dash: "dashed"), rest: 1pt))
+#set line(stroke: (dash: "dashed"))
+#line(stroke: (dash: none))
+
+---
+// Test the representation of dashed strokes.
+// Ref: false
+#let stroke(..args) = line(..args).stroke
+#test(repr(stroke(stroke: (dash: "dash-dotted"))), "(dash: \"dash-dotted\")")
+#test(repr(stroke(stroke: (thickness: 2pt, dash: "dashed"))), "(thickness: 2pt, dash: \"dashed\")")
+#test(repr(stroke(stroke: (paint: red, dash: none))), "(paint: rgb(\"#ff4136\"), dash: none)")
+
+---
+// Error: 15-29 expected "dotted", "dashed", "dash-dotted", or none
+#line(stroke: (dash: "wavy"))
+
+---
+// Error: 15-27 unexpected key "color", valid keys are "paint", "thickness", and "dash"
+#line(stroke: (color: red))
@@ -0,0 +1,19 @@
+// Test polygons.
+// Ref: false
+
+---
+// The polygon's size is the bounding box of its vertices and the origin.
+#style(styles => {
+  let size(..args) = measure(polygon(..args), styles)
+  test(size(), (width: 0pt, height: 0pt))
+  test(size((0pt, 0pt), (20pt, 10pt), (5pt, 30pt)), (width: 20pt, height: 30pt))
+  test(size((10pt, 5pt), (15pt, 5pt)), (width: 15pt, height: 5pt))
+})
+
+---
+#polygon(fill: red, (0pt, 0pt), (10pt, 0pt), (5pt, 10pt))
+#polygon(stroke: (thickness: 2pt, dash: "dotted"), (0pt, 0pt), (10pt, 10pt))
+
+---
+// Error: 10-16 point array must contain exactly two entries
+#polygon((0pt,))